@@ -1,8 +1,12 @@
 mod modules;
 
-use clap::{ArgAction, Args, Parser, Subcommand};
-use modules::subcommands;
-use std::process;
+use clap::{ArgAction, ArgGroup, Args, Parser, Subcommand};
+use modules::{
+    structs::config::Config,
+    subcommands,
+    types::{merge_strategy::MergeStrategy, sync_strategy::SyncStrategy},
+};
+use std::{path::PathBuf, process};
 
 #[derive(Parser)]
 #[clap(author, version, about)]
@@ -18,6 +22,16 @@ pub enum Action {
     New(NewArgs),
     RunCi,
     Ls,
+    Config(ConfigArgs),
+    Log(LogArgs),
+    Changelog(ChangelogArgs),
+    Release(ReleaseArgs),
+    Finish(FinishArgs),
+    Sync(SyncArgs),
+    Info(InfoArgs),
+    Rename(RenameArgs),
+    Delete(DeleteArgs),
+    Prune(PruneArgs),
 }
 
 #[derive(Args)]
@@ -25,42 +39,358 @@ pub struct NewArgs {
     #[clap(action)]
     pub branch_type: String,
 
-    #[clap(action)]
+    #[clap(action, help = "Ticket code, or the version of a release branch")]
     pub branch_code: String,
 
-    #[clap(action)]
-    pub branch_name: String,
+    #[clap(action, help = "Branch title, omitted for release branches")]
+    pub branch_name: Option<String>,
 
-    // ! FIX source should use branch_code
-    #[clap(action, short, long, conflicts_with = "from_current")]
+    #[clap(
+        action,
+        short,
+        long,
+        conflicts_with = "from_current",
+        help = "Code of the branch to start from"
+    )]
     pub source: Option<String>,
 
+    #[clap(
+        action,
+        long,
+        requires = "source",
+        conflicts_with = "literal_source",
+        help = "Only match source branches of this type"
+    )]
+    pub source_type: Option<String>,
+
+    #[clap(
+        action,
+        long,
+        value_name = "N",
+        requires = "source",
+        conflicts_with = "literal_source",
+        help = "Choose the Nth of several matching source branches"
+    )]
+    pub source_pick: Option<usize>,
+
     #[clap(action=ArgAction::SetTrue, short, long, requires = "source")]
     pub literal_source: bool,
 
     #[clap(action=ArgAction::SetTrue, short='c', long, help="Create branch from current branch", conflicts_with="source")]
     pub from_current: bool,
-    // TODO add --force option for duplicate code
+
+    #[clap(action=ArgAction::SetTrue, short, long, help="Create the branch even if its code is already used")]
+    pub force: bool,
+
+    #[clap(action=ArgAction::SetTrue, long, help="Create a follow-up branch with a numbered title for a code that is already used", conflicts_with="force")]
+    pub continue_ticket: bool,
+}
+
+#[derive(Args)]
+pub struct FinishArgs {
+    #[clap(
+        action,
+        help = "Code of the branch to finish, defaults to the current branch"
+    )]
+    pub branch_code: Option<String>,
+
+    #[clap(
+        action,
+        short = 't',
+        long = "type",
+        requires = "branch_code",
+        help = "Only match branches of this type"
+    )]
+    pub branch_type: Option<String>,
+
+    #[clap(
+        action,
+        short,
+        long,
+        requires = "branch_code",
+        value_name = "N",
+        help = "Choose the Nth of several matching branches"
+    )]
+    pub pick: Option<usize>,
+
+    #[clap(action, short, long, value_enum, help = "Overrides finish.strategy")]
+    pub strategy: Option<MergeStrategy>,
+
+    #[clap(action=ArgAction::SetTrue, long="continue", help="Resume a hotfix finish paused on a conflict", conflicts_with_all=["branch_code", "strategy", "abort"])]
+    pub continue_flow: bool,
+
+    #[clap(action=ArgAction::SetTrue, long, help="Stop a hotfix finish paused on a conflict", conflicts_with_all=["branch_code", "strategy"])]
+    pub abort: bool,
+}
+
+#[derive(Args)]
+pub struct SyncArgs {
+    #[clap(action, short, long, value_enum, help = "Overrides sync.strategy")]
+    pub strategy: Option<SyncStrategy>,
+
+    #[clap(action=ArgAction::SetTrue, long="continue", help="Resume a sync paused on a conflict", conflicts_with_all=["strategy", "abort"])]
+    pub continue_flow: bool,
+
+    #[clap(action=ArgAction::SetTrue, long, help="Stop a sync paused on a conflict and restore the branch", conflicts_with = "strategy")]
+    pub abort: bool,
+}
+
+#[derive(Args)]
+pub struct InfoArgs {
+    #[clap(
+        action,
+        help = "Code of the branch to describe, defaults to the current branch"
+    )]
+    pub branch_code: Option<String>,
+
+    #[clap(
+        action,
+        short = 't',
+        long = "type",
+        requires = "branch_code",
+        help = "Only match branches of this type"
+    )]
+    pub branch_type: Option<String>,
+
+    #[clap(
+        action,
+        short,
+        long,
+        requires = "branch_code",
+        value_name = "N",
+        help = "Choose the Nth of several matching branches"
+    )]
+    pub pick: Option<usize>,
+}
+
+#[derive(Args)]
+#[clap(group(ArgGroup::new("change").required(true).multiple(true).args(["code", "title", "branch_type"])))]
+pub struct RenameArgs {
+    #[clap(action, short, long, help = "New ticket code")]
+    pub code: Option<String>,
+
+    #[clap(action, long, help = "New branch title")]
+    pub title: Option<String>,
+
+    #[clap(action, short = 't', long = "type", help = "New branch type")]
+    pub branch_type: Option<String>,
+
+    #[clap(action=ArgAction::SetTrue, short, long, help="Do not touch the remote branch")]
+    pub local_only: bool,
+}
+
+#[derive(Args)]
+pub struct DeleteArgs {
+    #[clap(
+        action,
+        help = "Code of the branches to delete, locally and on every remote"
+    )]
+    pub branch_code: String,
+
+    #[clap(action=ArgAction::SetTrue, short, long, help="Delete branches that are not merged into their base")]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct PruneArgs {
+    #[clap(
+        action,
+        long,
+        value_name = "N",
+        help = "Also prune branches without commits in the last N days"
+    )]
+    pub days: Option<u64>,
+
+    #[clap(action=ArgAction::SetTrue, long, help="Only prune local branches")]
+    pub local: bool,
+
+    #[clap(action=ArgAction::SetTrue, long, help="Only prune branches on the push remote")]
+    pub remote: bool,
+
+    #[clap(action=ArgAction::SetTrue, short, long, help="List the branches without deleting them")]
+    pub dry_run: bool,
+
+    #[clap(action=ArgAction::SetTrue, long, help="Print the branches as JSON")]
+    pub json: bool,
+
+    #[clap(action=ArgAction::SetTrue, short, long, help="Delete the listed branches without asking")]
+    pub yes: bool,
+
+    #[clap(action=ArgAction::SetTrue, short, long, help="Also delete stale branches that are not merged")]
+    pub force: bool,
 }
 
 #[derive(Args)]
 pub struct CheckoutArgs {
-    #[clap(action)]
+    #[clap(
+        action,
+        help = "Ticket code, release version, title, branch name, tag or commit"
+    )]
     pub branch_code: String,
-    // TODO add --literal option
+
+    #[clap(action=ArgAction::SetTrue, short, long, help="Pass the input to git checkout as a branch name", conflicts_with_all=["branch_type", "pick"])]
+    pub literal: bool,
+
+    #[clap(
+        action,
+        short = 't',
+        long = "type",
+        help = "Only match branches of this type"
+    )]
+    pub branch_type: Option<String>,
+
+    #[clap(
+        action,
+        short,
+        long,
+        value_name = "N",
+        help = "Choose the Nth of several matching branches"
+    )]
+    pub pick: Option<usize>,
+}
+
+#[derive(Args)]
+pub struct ConfigArgs {
+    #[clap(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    #[clap(about = "Print the effective value of a key")]
+    Get(ConfigGetArgs),
+    #[clap(about = "Store a key in git config")]
+    Set(ConfigSetArgs),
+    #[clap(about = "Remove a key from git config")]
+    Unset(ConfigUnsetArgs),
+    #[clap(about = "List every effective setting")]
+    List(ConfigListArgs),
+}
+
+#[derive(Args)]
+#[group(multiple = false)]
+pub struct ConfigScopeArgs {
+    #[clap(action=ArgAction::SetTrue, long, help="Use the repository git config (default)")]
+    pub local: bool,
+
+    #[clap(action=ArgAction::SetTrue, long, help="Use the user git config")]
+    pub global: bool,
+
+    #[clap(action=ArgAction::SetTrue, long, help="Use the system git config")]
+    pub system: bool,
+}
+
+#[derive(Args)]
+pub struct ConfigGetArgs {
+    #[clap(action)]
+    pub key: String,
+}
+
+#[derive(Args)]
+pub struct ConfigSetArgs {
+    #[clap(action)]
+    pub key: String,
+
+    #[clap(action, help = "Lists are written comma separated")]
+    pub value: String,
+
+    #[clap(flatten)]
+    pub scope: ConfigScopeArgs,
+}
+
+#[derive(Args)]
+pub struct ConfigUnsetArgs {
+    #[clap(action)]
+    pub key: String,
+
+    #[clap(flatten)]
+    pub scope: ConfigScopeArgs,
+}
+
+#[derive(Args)]
+pub struct ConfigListArgs {
+    #[clap(action=ArgAction::SetTrue, long, help="Show where each value comes from")]
+    pub show_origin: bool,
+}
+
+#[derive(Args)]
+pub struct LogArgs {
+    #[clap(action, help = "Revision range to walk, defaults to HEAD")]
+    pub range: Option<String>,
+
+    #[clap(
+        action,
+        short = 't',
+        long = "type",
+        help = "Only show commits of this type"
+    )]
+    pub commit_type: Option<String>,
+
+    #[clap(action, short, long, help = "Only show commits of this ticket code")]
+    pub code: Option<String>,
+
+    #[clap(action, short, long, help = "Only show commits by a matching author")]
+    pub author: Option<String>,
+
+    #[clap(
+        action,
+        short,
+        long,
+        help = "Only show commits more recent than a date"
+    )]
+    pub since: Option<String>,
+}
+
+#[derive(Args)]
+pub struct ChangelogArgs {
+    #[clap(action, help = "Revision range such as v1.0.0..HEAD")]
+    pub range: String,
+
+    #[clap(
+        action,
+        short,
+        long,
+        help = "Section title, defaults to the end of the range"
+    )]
+    pub title: Option<String>,
+
+    #[clap(
+        action,
+        short,
+        long,
+        value_name = "FILE",
+        help = "Insert the section at the top of a changelog file instead of printing it"
+    )]
+    pub update: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct ReleaseArgs {
+    #[clap(
+        action,
+        long,
+        value_name = "ID",
+        help = "Create a pre-release such as 1.4.0-rc.1"
+    )]
+    pub pre: Option<String>,
+
+    #[clap(action=ArgAction::SetTrue, short, long, help="Print the version and the commits behind it without tagging")]
+    pub dry_run: bool,
 }
 
-// ! chore, docs, feat, fix, refactor, style, or test should be added in the future
 #[derive(Args)]
 pub struct CommitArgs {
     #[clap(
         action,
-        help = "Can be either \"feat\", \"fix\", \"style\" or \"chore\""
+        help = "A Conventional Commits type (feat, fix, docs, style, refactor, perf, test, build, ci, chore or revert) or a custom type from the config"
     )]
     pub commit_type: String,
 
-    #[clap(action)]
-    pub message: String,
+    #[clap(
+        action,
+        help = "Commit subject. Opens the editor on a prefilled template when omitted"
+    )]
+    pub message: Option<String>,
 
     #[clap(action=ArgAction::SetTrue, short, long, help="Run CI")]
     pub run_ci: bool,
@@ -70,19 +400,54 @@ pub struct CommitArgs {
 
     #[clap(action=ArgAction::SetTrue, short, long, help="Allow empty commit")]
     pub empty: bool,
+
+    #[clap(action, short, long, help = "Scope added before the branch code")]
+    pub scope: Option<String>,
+
+    #[clap(
+        action,
+        short,
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "",
+        value_name = "DESCRIPTION",
+        help = "Mark the commit as a breaking change, optionally with a BREAKING CHANGE footer"
+    )]
+    pub breaking: Option<String>,
+
+    #[clap(action=ArgAction::Append, short='m', long, help="Body paragraph, can be repeated")]
+    pub body: Vec<String>,
+
+    #[clap(action=ArgAction::Append, long, value_name="KEY=VALUE", help="Footer, can be repeated")]
+    pub footer: Vec<String>,
 }
 
 fn main() {
     let args = Cli::parse();
 
-    match args.action {
+    // the config subcommand must keep working when the config is broken
+    let init = match args.action {
+        Action::Config(_) => Ok(()),
+        _ => Config::init(),
+    };
+    init.and_then(|_| match args.action {
         Action::Commit(args) => subcommands::commit::exec(&args),
-        Action::Checkout(args) => subcommands::checkout::exec(&args.branch_code),
-        // TODO check for duplicate branch code
+        Action::Checkout(args) => subcommands::checkout::exec(&args),
         Action::New(args) => subcommands::new::exec(&args),
         Action::Ls => subcommands::ls::exec(),
         Action::RunCi => subcommands::run_ci::exec(),
-    }
+        Action::Config(args) => subcommands::config::exec(&args),
+        Action::Log(args) => subcommands::log::exec(&args),
+        Action::Changelog(args) => subcommands::changelog::exec(&args),
+        Action::Release(args) => subcommands::release::exec(&args),
+        Action::Finish(args) => subcommands::finish::exec(&args),
+        Action::Sync(args) => subcommands::sync::exec(&args),
+        Action::Info(args) => subcommands::info::exec(&args),
+        Action::Rename(args) => subcommands::rename::exec(&args),
+        Action::Delete(args) => subcommands::delete::exec(&args),
+        Action::Prune(args) => subcommands::prune::exec(&args),
+    })
     .unwrap_or_else(|error| {
         println!("{}", error);
         process::exit(-1);
@@ -1,4 +1,4 @@
 pub mod subcommands;
 pub mod types;
 mod helpers;
-mod structs;
\ No newline at end of file
+pub mod structs;
\ No newline at end of file
@@ -1,2 +1,10 @@
 pub mod branch;
-pub mod push;
\ No newline at end of file
+pub mod editor;
+pub mod fuzzy;
+pub mod git_config;
+pub mod log;
+pub mod push;
+pub mod remote;
+pub mod repo;
+pub mod state;
+pub mod tag;
\ No newline at end of file
@@ -1,7 +1,19 @@
-use std::{collections::HashSet, process::Command};
+#[cfg(test)]
+mod tests;
 
-use crate::modules::{structs::branch::Branch, types::errors::Error};
+use std::{collections::BTreeSet, process::Command};
 
+use crate::modules::{
+    helpers::{
+        remote::{base_remote, find_remote_branch, get_remotes},
+        repo::{check_git, ref_exists, run_git},
+    },
+    structs::{branch::Branch, branch_metadata::BranchMetadata, config::Config},
+    types::errors::Error,
+};
+
+/// Lists local and remote branches sorted by name. A local branch and its
+/// copies on every remote share one entry.
 pub fn get_branches() -> Result<Vec<String>, Error> {
     let branches_output = Command::new("git")
         .arg("branch")
@@ -12,37 +24,48 @@ pub fn get_branches() -> Result<Vec<String>, Error> {
         return Err(Error::Git);
     };
     let branches = String::from_utf8(branches_output.stdout).map_err(|_| Error::Git)?;
+    Ok(normalize_branch_names(&branches, &get_remotes()?))
+}
+
+/// Turns `git branch -a` output into sorted branch names without the
+/// `remotes/<remote>/` prefix of any of `remotes`, so a branch on several
+/// remotes and locally is listed once.
+fn normalize_branch_names(branches: &str, remotes: &[String]) -> Vec<String> {
+    let remote_prefixes = remotes
+        .iter()
+        .map(|remote| format!("remotes/{}/", remote))
+        .collect::<Vec<String>>();
     // !! performance should be investigated
-    let branches = branches
+    branches
         .trim()
-        .split("\n")
+        .split('\n')
         .map(|branch| {
             let branch = branch.trim();
-            if branch.starts_with("*") {
-                (&branch[2..]).to_string()
+            if let Some(branch) = branch.strip_prefix("* ") {
+                branch.to_string()
             } else if branch.contains(" -> ") {
                 let i = branch.find(" -> ").unwrap_or(branch.len());
-                (&branch[0..i]).to_string()
-            } else if branch.starts_with(Branch::REMOTE_PREFIX) {
-                (&branch[Branch::REMOTE_PREFIX.len()..]).to_string()
+                branch[0..i].to_string()
+            } else if let Some(branch) = remote_prefixes
+                .iter()
+                .find_map(|prefix| branch.strip_prefix(prefix.as_str()))
+            {
+                branch.to_string()
             } else {
                 branch.to_string()
             }
         })
-        .collect::<HashSet<String>>()
+        .collect::<BTreeSet<String>>()
         .into_iter()
-        .collect::<Vec<String>>();
-    Ok(branches)
+        .collect::<Vec<String>>()
 }
 
-pub fn filter_branches_by_code<'a>(
-    branches: &'a Vec<String>,
-    branch_code: &str,
-) -> Vec<&'a String> {
+pub fn filter_branches_by_code<'a>(branches: &'a [String], branch_code: &str) -> Vec<&'a String> {
+    let branch_code = Config::get().branch.code_format.normalize(branch_code);
     branches
         .iter()
         .filter(|branch_name| {
-            if let Ok(branch) = Branch::from(&branch_name) {
+            if let Ok(branch) = Branch::from(branch_name) {
                 branch.code() == branch_code
             } else {
                 false
@@ -50,3 +73,134 @@ pub fn filter_branches_by_code<'a>(
         })
         .collect::<Vec<&String>>()
 }
+
+/// Lists the names under a ref namespace such as `refs/heads/`, without the
+/// namespace.
+pub fn list_refs(namespace: &str) -> Result<Vec<String>, Error> {
+    let output = Command::new("git")
+        .arg("for-each-ref")
+        .arg("--format=%(refname)")
+        .arg(namespace)
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let refs = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(refs
+        .lines()
+        .filter_map(|reference| reference.strip_prefix(namespace))
+        .filter(|name| *name != "HEAD")
+        .map(|name| name.to_string())
+        .collect())
+}
+
+/// Reports whether every commit of `reference` is already in `base`.
+pub fn is_merged(reference: &str, base: &str) -> Result<bool, Error> {
+    check_git(&["merge-base", "--is-ancestor", reference, base])
+}
+
+/// Reports whether `reference`, the local or remote copy of the branch
+/// `name`, is merged into the base of the branch or its remote copy.
+pub fn is_merged_into_base(reference: &str, name: &str, branch_type: &str) -> Result<bool, Error> {
+    let base = match get_branch_base(name, branch_type) {
+        Ok(base) => base,
+        Err(Error::BaseBranchNotFound(_)) => return Ok(false),
+        Err(error) => return Err(error),
+    };
+    Ok(is_merged(reference, &base)?
+        || is_merged(reference, &format!("{}/{}", base_remote(), base))?)
+}
+
+/// Checks out the branch `name`. When only a remote has it, a local branch
+/// tracking the remote copy is created.
+pub fn checkout_branch(name: &str) -> Result<(), Error> {
+    if !ref_exists(&format!("refs/heads/{}", name))? {
+        if let Some(remote_branch) = find_remote_branch(name)? {
+            return run_git(&["checkout", "--track", "-b", name, &remote_branch]);
+        }
+    }
+    run_git(&["checkout", name])
+}
+
+/// Resolves the single branch carrying `branch_code`, optionally of
+/// `branch_type`. When several match, `pick` chooses one of them by its
+/// 1-based position in the sorted list.
+pub fn resolve_branch(
+    branch_code: &str,
+    branch_type: Option<&str>,
+    pick: Option<usize>,
+) -> Result<String, Error> {
+    select_branch(&get_branches()?, branch_code, branch_type, pick)
+}
+
+/// Does the matching of `resolve_branch` over `branches`, as listed by
+/// `get_branches`. A local branch and its remote copies are a single entry
+/// there, so a branch is never ambiguous with its own remote-tracking copy
+/// and `checkout_branch` prefers the local one.
+fn select_branch(
+    branches: &[String],
+    branch_code: &str,
+    branch_type: Option<&str>,
+    pick: Option<usize>,
+) -> Result<String, Error> {
+    let matches = filter_branches_by_code(branches, branch_code)
+        .into_iter()
+        .filter(|name| {
+            branch_type.is_none_or(|branch_type| {
+                Branch::from(name).is_ok_and(|branch| branch.branch_type() == branch_type)
+            })
+        })
+        .cloned()
+        .collect::<Vec<String>>();
+    match (matches.len(), pick) {
+        (0, _) => Err(Error::BranchNotFoundOnCheckout(branch_code.to_string())),
+        (_, Some(pick)) => matches
+            .get(pick.wrapping_sub(1))
+            .cloned()
+            .ok_or(Error::InvalidPick(pick, matches.len())),
+        (1, None) => Ok(matches[0].clone()),
+        (_, None) => Err(Error::AmbiguousCode(branch_code.to_string(), matches)),
+    }
+}
+
+/// Resolves the branch `name` was created from, falling back to the
+/// configured base of its type for branches without stored metadata.
+pub fn get_branch_base(name: &str, branch_type: &str) -> Result<String, Error> {
+    match BranchMetadata::read(name)? {
+        Some(metadata) if !metadata.source.is_empty() => Ok(metadata.source),
+        _ => get_base_branch(branch_type),
+    }
+}
+
+/// Resolves the branch new branches of `branch_type` start from: the
+/// configured `branch.base.<type>`, or the remote's default branch when the
+/// type has no mapping.
+pub fn get_base_branch(branch_type: &str) -> Result<String, Error> {
+    if let Some(base) = Config::get().branch.base.get(branch_type) {
+        return Ok(base.clone());
+    }
+    get_remote_default_branch()?.ok_or_else(|| Error::BaseBranchNotFound(branch_type.to_string()))
+}
+
+fn get_remote_default_branch() -> Result<Option<String>, Error> {
+    let output = Command::new("git")
+        .arg("symbolic-ref")
+        .arg("--quiet")
+        .arg("--short")
+        .arg(format!("refs/remotes/{}/HEAD", base_remote()))
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Ok(None);
+    }
+    let default_branch = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    // `origin/main` names the branch `main`, which callers resolve to a ref
+    let default_branch = default_branch.trim();
+    Ok(Some(
+        default_branch
+            .strip_prefix(&format!("{}/", base_remote()))
+            .unwrap_or(default_branch)
+            .to_string(),
+    ))
+}
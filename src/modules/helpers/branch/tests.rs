@@ -0,0 +1,96 @@
+use super::*;
+
+fn branches() -> Vec<String> {
+    [
+        "develop",
+        "feature/12_login",
+        "feature/13_logout",
+        "hotfix/12_login-crash",
+        "master",
+    ]
+    .map(str::to_string)
+    .to_vec()
+}
+
+#[test]
+fn test_select_branch_unique() -> Result<(), Error> {
+    assert_eq!(
+        select_branch(&branches(), "13", None, None)?,
+        "feature/13_logout"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_select_branch_not_found() {
+    assert!(matches!(
+        select_branch(&branches(), "14", None, None),
+        Err(Error::BranchNotFoundOnCheckout(code)) if code == "14"
+    ));
+}
+
+#[test]
+fn test_select_branch_ambiguous() {
+    assert!(matches!(
+        select_branch(&branches(), "12", None, None),
+        Err(Error::AmbiguousCode(code, matches))
+            if code == "12" && matches == vec!["feature/12_login", "hotfix/12_login-crash"]
+    ));
+}
+
+#[test]
+fn test_select_branch_type() -> Result<(), Error> {
+    assert_eq!(
+        select_branch(&branches(), "12", Some("hotfix"), None)?,
+        "hotfix/12_login-crash"
+    );
+    assert!(matches!(
+        select_branch(&branches(), "13", Some("hotfix"), None),
+        Err(Error::BranchNotFoundOnCheckout(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_select_branch_pick() -> Result<(), Error> {
+    assert_eq!(
+        select_branch(&branches(), "12", None, Some(2))?,
+        "hotfix/12_login-crash"
+    );
+    assert!(matches!(
+        select_branch(&branches(), "12", None, Some(0)),
+        Err(Error::InvalidPick(0, 2))
+    ));
+    assert!(matches!(
+        select_branch(&branches(), "12", None, Some(3)),
+        Err(Error::InvalidPick(3, 2))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_normalize_branch_names() {
+    let output = "\
+* feature/12_login
+  develop
+  remotes/fork/feature/12_login
+  remotes/fork/feature/13_logout
+  remotes/upstream/develop
+  remotes/upstream/feature/13_logout
+  remotes/upstream/master
+";
+    let remotes = ["fork", "upstream"].map(str::to_string);
+    assert_eq!(
+        normalize_branch_names(output, &remotes),
+        vec!["develop", "feature/12_login", "feature/13_logout", "master"]
+    );
+}
+
+#[test]
+fn test_normalize_branch_names_unknown_remote() {
+    let output = "  develop\n  remotes/other/develop\n";
+    assert_eq!(
+        normalize_branch_names(output, &["fork".to_string()]),
+        vec!["develop", "remotes/other/develop"]
+    );
+}
@@ -0,0 +1,39 @@
+use std::{fs, path::Path, process::Command};
+
+use crate::modules::types::errors::Error;
+
+/// Writes `content` to `path`, opens it in the editor git would use and
+/// returns the edited content.
+pub fn edit(path: &Path, content: &str) -> Result<String, Error> {
+    fs::write(path, content).map_err(|_| Error::Editor)?;
+    let editor = get_editor()?;
+    // run through the shell like git does, so editors with arguments work
+    let exit_code = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$@\"", editor))
+        .arg(&editor)
+        .arg(path)
+        .status()
+        .map_err(|_| Error::Editor)?
+        .code()
+        .unwrap_or(-1);
+    if exit_code != 0 {
+        return Err(Error::Editor);
+    }
+    fs::read_to_string(path).map_err(|_| Error::Editor)
+}
+
+/// Resolves `$GIT_EDITOR`, `core.editor`, `$VISUAL` and `$EDITOR` in git's
+/// order.
+fn get_editor() -> Result<String, Error> {
+    let output = Command::new("git")
+        .arg("var")
+        .arg("GIT_EDITOR")
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Editor);
+    }
+    let editor = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(editor.trim().to_string())
+}
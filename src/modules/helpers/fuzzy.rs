@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests;
+
+const MATCH: i64 = 1;
+const CONSECUTIVE: i64 = 4;
+const WORD_START: i64 = 3;
+const TEXT_START: i64 = 2;
+const GAP: i64 = 1;
+
+/// Scores how well `query` matches `text` as a case-insensitive subsequence,
+/// or `None` when it does not match. Runs of consecutive characters and
+/// matches at the start of words, which `-`, `_` and spaces separate, or of
+/// the text score higher, skipped characters lower.
+pub fn score(query: &str, text: &str) -> Option<i64> {
+    let normalize = |raw: &str| {
+        raw.to_lowercase()
+            .chars()
+            .map(|c| if c == '_' || c == ' ' { '-' } else { c })
+            .collect::<Vec<char>>()
+    };
+    let query = normalize(query.trim());
+    let text = normalize(text);
+    if query.is_empty() {
+        return None;
+    }
+
+    // best[j] is the best score of the query so far with its last character
+    // matched at text[j]
+    let mut best: Vec<Option<i64>> = vec![None; text.len()];
+    for (i, query_char) in query.iter().enumerate() {
+        let mut next = vec![None; text.len()];
+        for (j, text_char) in text.iter().enumerate() {
+            if query_char != text_char {
+                continue;
+            }
+            let bonus = MATCH
+                + if j == 0 || text[j - 1] == '-' {
+                    WORD_START
+                } else {
+                    0
+                };
+            next[j] = if i == 0 {
+                Some(bonus + if j == 0 { TEXT_START } else { 0 })
+            } else {
+                (0..j)
+                    .filter_map(|k| {
+                        let previous = best[k]?;
+                        let gap = (j - k - 1) as i64;
+                        let run = if gap == 0 { CONSECUTIVE } else { -gap * GAP };
+                        Some(previous + bonus + run)
+                    })
+                    .max()
+            };
+        }
+        best = next;
+    }
+    best.into_iter().flatten().max()
+}
@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn test_score_no_match() {
+    assert_eq!(score("timeout-login", "login-timeout"), None);
+    assert_eq!(score("xyz", "login-timeout"), None);
+    assert_eq!(score("", "login-timeout"), None);
+}
+
+#[test]
+fn test_score_ranking() {
+    let exact = score("login-timeout", "login-timeout");
+    let words = score("login timeout", "fix-login-timeout");
+    let initials = score("lt", "login-timeout");
+    let scattered = score("lt", "allot");
+    assert!(exact > words);
+    assert!(initials > scattered);
+    assert!(score("timeout", "login-timeout") > score("timeout", "time-of-output"));
+}
+
+#[test]
+fn test_score_separators() {
+    assert_eq!(
+        score("login_timeout", "login-timeout"),
+        score("Login Timeout", "login-timeout")
+    );
+}
@@ -0,0 +1,92 @@
+use std::process::Command;
+
+use crate::modules::types::errors::Error;
+
+/// A git config variable as reported by `git config --show-scope --show-origin`.
+pub struct GitConfigEntry {
+    pub scope: String,
+    pub origin: String,
+    pub key: String,
+    pub value: String,
+}
+
+/// Lists the variables whose name starts with `prefix`, from every scope, in
+/// the order git applies them.
+pub fn list(prefix: &str) -> Result<Vec<GitConfigEntry>, Error> {
+    let output = Command::new("git")
+        .arg("config")
+        .arg("--show-scope")
+        .arg("--show-origin")
+        .arg("--null")
+        .arg("--get-regexp")
+        .arg(format!("^{}", escape_regex(prefix)))
+        .output()
+        .map_err(|_| Error::Git)?;
+    match output.status.code().unwrap_or(-1) {
+        0 => {}
+        // exit code 1 means no variable matched
+        1 => return Ok(vec![]),
+        _ => return Err(Error::Git),
+    }
+    let output = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    let mut fields = output.split('\0');
+    let mut entries = vec![];
+    while let (Some(scope), Some(origin), Some(key_value)) =
+        (fields.next(), fields.next(), fields.next())
+    {
+        let (key, value) = key_value.split_once('\n').unwrap_or((key_value, ""));
+        entries.push(GitConfigEntry {
+            scope: scope.to_string(),
+            origin: origin.to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+        });
+    }
+    Ok(entries)
+}
+
+fn escape_regex(raw: &str) -> String {
+    raw.chars()
+        .map(|c| {
+            if "\\.^$*+?()[]{}|".contains(c) {
+                format!("\\{}", c)
+            } else {
+                c.to_string()
+            }
+        })
+        .collect()
+}
+
+pub fn set(scope: &str, key: &str, value: &str) -> Result<(), Error> {
+    let exit_code = Command::new("git")
+        .arg("config")
+        .arg(format!("--{}", scope))
+        .arg(key)
+        .arg(value)
+        .status()
+        .map_err(|_| Error::Git)?
+        .code()
+        .unwrap_or(-1);
+    if exit_code != 0 {
+        return Err(Error::Git);
+    }
+    Ok(())
+}
+
+pub fn unset(scope: &str, key: &str) -> Result<(), Error> {
+    let exit_code = Command::new("git")
+        .arg("config")
+        .arg(format!("--{}", scope))
+        .arg("--unset")
+        .arg(key)
+        .status()
+        .map_err(|_| Error::Git)?
+        .code()
+        .unwrap_or(-1);
+    match exit_code {
+        0 => Ok(()),
+        // exit code 5 means the key was not set in this scope
+        5 => Err(Error::ConfigKeyNotSet(key.to_string())),
+        _ => Err(Error::Git),
+    }
+}
@@ -0,0 +1,43 @@
+use std::process::Command;
+
+use crate::modules::types::errors::Error;
+
+pub struct LogEntry {
+    pub sha: String,
+    pub author: String,
+    pub date: String,
+    pub message: String,
+}
+
+const FIELD_SEPARATOR: char = '\x1f';
+const ENTRY_SEPARATOR: char = '\x1e';
+
+/// Reads the commits of `range`, newest first. `extra_args` are passed to
+/// `git log` as is.
+pub fn get_log(range: &str, extra_args: &[String]) -> Result<Vec<LogEntry>, Error> {
+    let output = Command::new("git")
+        .arg("log")
+        .arg("--format=%H%x1f%an%x1f%as%x1f%B%x1e")
+        .args(extra_args)
+        .arg(range)
+        .arg("--")
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let output = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    let entries = output
+        .split(ENTRY_SEPARATOR)
+        .filter_map(|entry| {
+            let mut fields = entry.trim_start().splitn(4, FIELD_SEPARATOR);
+            Some(LogEntry {
+                sha: fields.next()?.to_string(),
+                author: fields.next()?.to_string(),
+                date: fields.next()?.to_string(),
+                message: fields.next()?.trim().to_string(),
+            })
+        })
+        .collect();
+    Ok(entries)
+}
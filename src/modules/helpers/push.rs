@@ -1,12 +1,12 @@
 use std::process::Command;
 
-use crate::modules::types::errors::Error;
+use crate::modules::{helpers::remote::push_remote, types::errors::Error};
 
 pub fn git_push() -> Result<(), Error> {
     let status = Command::new("git")
         .arg("push")
         .arg("-u")
-        .arg("origin")
+        .arg(push_remote())
         .arg("HEAD")
         .status()
         .map_err(|_| Error::Git)?;
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests;
+
+use std::process::Command;
+
+use crate::modules::{
+    helpers::repo::ref_exists,
+    structs::{branch::Branch, config::Config},
+    types::errors::Error,
+};
+
+pub fn get_remotes() -> Result<Vec<String>, Error> {
+    let output = Command::new("git")
+        .arg("remote")
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let remotes = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(remotes.lines().map(|remote| remote.to_string()).collect())
+}
+
+pub fn base_remote() -> &'static str {
+    &Config::get().remote.base
+}
+
+pub fn push_remote() -> &'static str {
+    &Config::get().remote.push
+}
+
+/// Finds the remote-tracking copy of the branch `name`. Ticket branches are
+/// looked up on the push remote first and base branches such as `develop`
+/// on the base remote first, then every other remote.
+pub fn find_remote_branch(name: &str) -> Result<Option<String>, Error> {
+    for remote in get_search_order(name, base_remote(), push_remote(), &get_remotes()?) {
+        let remote_branch = format!("{}/{}", remote, name);
+        if ref_exists(&format!("refs/remotes/{}", remote_branch))? {
+            return Ok(Some(remote_branch));
+        }
+    }
+    Ok(None)
+}
+
+fn get_search_order(name: &str, base: &str, push: &str, remotes: &[String]) -> Vec<String> {
+    let is_ticket =
+        Branch::from(name).is_ok_and(|branch| !branch.is_special() && !branch.is_release());
+    let mut order = if is_ticket {
+        vec![push.to_string(), base.to_string()]
+    } else {
+        vec![base.to_string(), push.to_string()]
+    };
+    for remote in remotes {
+        if !order.contains(remote) {
+            order.push(remote.clone());
+        }
+    }
+    order.dedup();
+    order
+}
+
+/// Returns `name` when it is a local branch, or else its remote-tracking
+/// copy. Anything else is returned as is for git to resolve.
+pub fn resolve_ref(name: &str) -> Result<String, Error> {
+    if ref_exists(&format!("refs/heads/{}", name))? {
+        return Ok(name.to_string());
+    }
+    Ok(find_remote_branch(name)?.unwrap_or_else(|| name.to_string()))
+}
@@ -0,0 +1,38 @@
+use super::*;
+
+fn remotes() -> Vec<String> {
+    ["fork", "mirror", "upstream"].map(str::to_string).to_vec()
+}
+
+#[test]
+fn test_search_order_ticket_branch() {
+    assert_eq!(
+        get_search_order("feature/12_login", "upstream", "fork", &remotes()),
+        vec!["fork", "upstream", "mirror"]
+    );
+}
+
+#[test]
+fn test_search_order_base_branch() {
+    assert_eq!(
+        get_search_order("develop", "upstream", "fork", &remotes()),
+        vec!["upstream", "fork", "mirror"]
+    );
+    assert_eq!(
+        get_search_order("release/1.4.0", "upstream", "fork", &remotes()),
+        vec!["upstream", "fork", "mirror"]
+    );
+}
+
+#[test]
+fn test_search_order_single_remote() {
+    assert_eq!(
+        get_search_order(
+            "feature/12_login",
+            "origin",
+            "origin",
+            &["origin".to_string()]
+        ),
+        vec!["origin"]
+    );
+}
@@ -0,0 +1,117 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::modules::types::errors::Error;
+
+pub fn get_git_dir() -> Result<PathBuf, Error> {
+    let output = Command::new("git")
+        .arg("rev-parse")
+        .arg("--git-dir")
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let git_dir = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(PathBuf::from(git_dir.trim()))
+}
+
+pub fn get_root() -> Result<PathBuf, Error> {
+    let output = Command::new("git")
+        .arg("rev-parse")
+        .arg("--show-toplevel")
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let root = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(PathBuf::from(root.trim()))
+}
+
+/// Resolves a revision to the full SHA of its commit.
+pub fn rev_parse(revision: &str) -> Result<String, Error> {
+    let output = Command::new("git")
+        .arg("rev-parse")
+        .arg("--verify")
+        .arg("--quiet")
+        .arg(format!("{}^{{commit}}", revision))
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let sha = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(sha.trim().to_string())
+}
+
+/// Commits the current content of `paths` only, leaving anything else that
+/// is staged out of the commit.
+pub fn commit_paths(paths: &[&Path], message: &str) -> Result<(), Error> {
+    let exit_code = Command::new("git")
+        .arg("commit")
+        .arg("-m")
+        .arg(message)
+        .arg("--")
+        .args(paths)
+        .status()
+        .map_err(|_| Error::Git)?
+        .code()
+        .unwrap_or(-1);
+    if exit_code != 0 {
+        return Err(Error::Commit);
+    }
+    Ok(())
+}
+
+/// Runs git with its output going to the terminal.
+pub fn run_git(args: &[&str]) -> Result<(), Error> {
+    let exit_code = Command::new("git")
+        .args(args)
+        .status()
+        .map_err(|_| Error::Git)?
+        .code()
+        .unwrap_or(-1);
+    if exit_code != 0 {
+        return Err(Error::Git);
+    }
+    Ok(())
+}
+
+/// Runs git quietly and reports whether it succeeded.
+pub fn check_git(args: &[&str]) -> Result<bool, Error> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|_| Error::Git)?;
+    Ok(output.status.code().unwrap_or(-1) == 0)
+}
+
+pub fn ref_exists(reference: &str) -> Result<bool, Error> {
+    check_git(&["show-ref", "--verify", "--quiet", reference])
+}
+
+/// Reports whether the work tree and the index have no changes to tracked
+/// or untracked files.
+pub fn is_clean() -> Result<bool, Error> {
+    let output = Command::new("git")
+        .arg("status")
+        .arg("--porcelain")
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    Ok(output.stdout.iter().all(|c| c.is_ascii_whitespace()))
+}
+
+pub fn is_merging() -> Result<bool, Error> {
+    check_git(&["rev-parse", "--quiet", "--verify", "MERGE_HEAD"])
+}
+
+pub fn is_rebasing() -> Result<bool, Error> {
+    let git_dir = get_git_dir()?;
+    Ok(git_dir.join("rebase-merge").exists() || git_dir.join("rebase-apply").exists())
+}
@@ -0,0 +1,43 @@
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use crate::modules::{helpers::repo::get_git_dir, types::errors::Error};
+
+/// Paused multi step operations keep their state as `key=value` lines in
+/// `.git/sam/<name>`.
+fn get_path(name: &str) -> Result<PathBuf, Error> {
+    Ok(get_git_dir()?.join("sam").join(name))
+}
+
+pub fn read_state(name: &str) -> Result<Option<BTreeMap<String, String>>, Error> {
+    let path = get_path(name)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path).map_err(|_| Error::State(name.to_string()))?;
+    let state = content
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    Ok(Some(state))
+}
+
+pub fn write_state(name: &str, state: &BTreeMap<String, String>) -> Result<(), Error> {
+    let path = get_path(name)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|_| Error::State(name.to_string()))?;
+    }
+    let content = state
+        .iter()
+        .map(|(key, value)| format!("{}={}\n", key, value))
+        .collect::<String>();
+    fs::write(&path, content).map_err(|_| Error::State(name.to_string()))
+}
+
+pub fn clear_state(name: &str) -> Result<(), Error> {
+    let path = get_path(name)?;
+    if path.exists() {
+        fs::remove_file(&path).map_err(|_| Error::State(name.to_string()))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests;
+
+use std::process::Command;
+
+use crate::modules::{
+    structs::{config::Config, version::Version},
+    types::errors::Error,
+};
+
+/// Lists the tags that are a version with the configured tag prefix,
+/// newest version first.
+pub fn get_version_tags() -> Result<Vec<(String, Version)>, Error> {
+    list_version_tags(&[])
+}
+
+/// Returns the newest tag reachable from `target` that is not a
+/// pre-release, so tags on other release lines are ignored.
+pub fn get_last_release_tag(target: &str) -> Result<Option<(String, Version)>, Error> {
+    Ok(find_last_release(list_version_tags(&["--merged", target])?))
+}
+
+fn list_version_tags(args: &[&str]) -> Result<Vec<(String, Version)>, Error> {
+    let output = Command::new("git")
+        .arg("tag")
+        .arg("--list")
+        .args(args)
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let tags = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(parse_version_tags(&tags, &Config::get().release.tag_prefix))
+}
+
+fn parse_version_tags(tags: &str, prefix: &str) -> Vec<(String, Version)> {
+    let mut tags = tags
+        .lines()
+        .filter_map(|tag| {
+            let version = Version::parse(tag.trim().strip_prefix(prefix)?).ok()?;
+            Some((tag.trim().to_string(), version))
+        })
+        .collect::<Vec<(String, Version)>>();
+    tags.sort_by(|(_, a), (_, b)| b.cmp(a));
+    tags
+}
+
+fn find_last_release(tags: Vec<(String, Version)>) -> Option<(String, Version)> {
+    tags.into_iter()
+        .find(|(_, version)| !version.is_pre_release())
+}
+
+pub fn make_tag_name(version: &Version) -> String {
+    format!("{}{}", Config::get().release.tag_prefix, version)
+}
+
+pub fn create_annotated_tag(name: &str, message: &str) -> Result<(), Error> {
+    let exit_code = Command::new("git")
+        .arg("tag")
+        .arg("-a")
+        .arg(name)
+        .arg("-m")
+        .arg(message)
+        .status()
+        .map_err(|_| Error::Git)?
+        .code()
+        .unwrap_or(-1);
+    if exit_code != 0 {
+        return Err(Error::Git);
+    }
+    Ok(())
+}
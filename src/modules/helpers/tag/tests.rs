@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn test_parse_version_tags() -> Result<(), Error> {
+    let tags = parse_version_tags("v1.9.0\nv1.10.0\nnot-a-version\n1.11.0\nv2.0.0-rc.1\n", "v");
+    assert_eq!(
+        tags,
+        vec![
+            ("v2.0.0-rc.1".to_string(), Version::parse("2.0.0-rc.1")?),
+            ("v1.10.0".to_string(), Version::parse("1.10.0")?),
+            ("v1.9.0".to_string(), Version::parse("1.9.0")?),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_find_last_release() -> Result<(), Error> {
+    let tags = parse_version_tags("v1.2.0\nv1.3.0-rc.2\nv1.2.1\n", "v");
+    assert_eq!(
+        find_last_release(tags),
+        Some(("v1.2.1".to_string(), Version::parse("1.2.1")?))
+    );
+    assert_eq!(
+        find_last_release(parse_version_tags("v1.0.0-rc.1", "v")),
+        None
+    );
+    Ok(())
+}
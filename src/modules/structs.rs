@@ -1 +1,7 @@
-pub mod branch;
\ No newline at end of file
+pub mod branch;
+pub mod branch_metadata;
+pub mod commit_message;
+pub mod commit_options;
+pub mod config;
+pub mod version;
+pub mod version_file;
\ No newline at end of file
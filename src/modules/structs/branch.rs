@@ -3,7 +3,13 @@ mod tests;
 
 use std::process::Command;
 
-use crate::modules::types::{commit_types::CommitType, errors::Error};
+use crate::modules::{
+    structs::{
+        commit_message::CommitMessage, commit_options::CommitOptions, config::Config,
+        version::Version,
+    },
+    types::{commit_types::CommitType, errors::Error},
+};
 
 #[derive(PartialEq, Debug)]
 pub struct Branch {
@@ -14,82 +20,109 @@ pub struct Branch {
 }
 
 impl Branch {
-    pub const VALID_TYPES: [&'static str; 2] = ["feature", "hotfix"];
-    pub const SPECIAL_NAMES: [&'static str; 3] = ["develop", "main", "master"];
-    pub const CODE_PREFIX: &'static str = "";
     pub const RUN_CI: &'static str = "(run_ci)";
-    pub const REMOTE_PREFIX: &'static str = "remotes/origin/";
 
     pub fn make_raw_name(
         branch_type: &str,
         branch_code: &str,
         branch_name: &str,
     ) -> Result<String, Error> {
-        if Branch::SPECIAL_NAMES.contains(&branch_name)
-            && branch_code.is_empty()
-            && branch_type.is_empty()
-        {
+        let config = &Config::get().branch;
+        if config.is_special(branch_name) && branch_code.is_empty() && branch_type.is_empty() {
             return Ok(branch_name.to_string());
         }
-        if !branch_code.bytes().all(|c| c.is_ascii_digit()) {
+        if config.is_release_type(branch_type) {
+            Version::parse(branch_code)?;
+            if !branch_name.is_empty() {
+                return Err(Error::NameFormat);
+            }
+            return Ok(format!("{}/{}", branch_type, branch_code));
+        }
+        if branch_name.is_empty() {
+            return Err(Error::NameFormat);
+        }
+        let branch_code = config.code_format.normalize(branch_code);
+        if !config.code_format.is_valid(&branch_code) {
             return Err(Error::BranchCode);
         }
-        if !Branch::VALID_TYPES.contains(&branch_type) {
+        if !config.is_valid_type(branch_type) {
             return Err(Error::InvalidBranchType(branch_type.to_string()));
         }
         let raw_name = format!(
             "{}/{}{}_{}",
-            branch_type,
-            Branch::CODE_PREFIX,
-            branch_code,
-            branch_name
-        )
-        .to_string();
+            branch_type, config.code_prefix, branch_code, branch_name
+        );
         Ok(raw_name)
     }
 
-    fn make_commit_message(&self, commit_type: CommitType, message: &str, run_ci: bool) -> String {
-        let commit_type = match commit_type {
-            CommitType::Chore => "chore",
-            CommitType::Feat => "feat",
-            CommitType::Fix => "fix",
-            CommitType::Style => "style",
-        };
-        if self.is_special {
-            format!(
-                "{}: {}{}",
-                commit_type,
-                message,
-                if run_ci {
-                    " ".to_string() + Self::RUN_CI
-                } else {
-                    "".to_string()
-                }
-            )
+    pub fn make_commit_message(
+        &self,
+        commit_type: CommitType,
+        message: &str,
+        run_ci: bool,
+        options: &CommitOptions,
+    ) -> String {
+        // release branches carry their version where others carry the code
+        let code = if self.is_release() {
+            self.branch_code.clone()
         } else {
-            format!(
-                "{}(#{}): {}{}",
-                commit_type,
-                self.branch_code,
-                message.trim(),
-                if run_ci {
-                    format!(" {}", Self::RUN_CI)
-                } else {
-                    "".to_string()
-                }
-            )
+            format!("#{}", self.branch_code)
+        };
+        let (scope, message) = match (&options.scope, self.is_special) {
+            (None, true) => ("".to_string(), message),
+            (Some(scope), true) => (format!("({})", scope), message),
+            (None, false) => (format!("({})", code), message.trim()),
+            (Some(scope), false) => (format!("({},{})", scope, code), message.trim()),
+        };
+        let mut commit_message = format!(
+            "{}{}{}: {}{}",
+            commit_type.as_str(),
+            scope,
+            if options.is_breaking() { "!" } else { "" },
+            message,
+            if run_ci {
+                format!(" {}", Self::RUN_CI)
+            } else {
+                "".to_string()
+            }
+        );
+        for paragraph in options.body.iter().map(|paragraph| paragraph.trim()) {
+            if !paragraph.is_empty() {
+                commit_message += &format!("\n\n{}", paragraph);
+            }
+        }
+        if !options.footers.is_empty() {
+            let footers = options
+                .footers
+                .iter()
+                .map(|(key, value)| format!("{}: {}", key, value.trim()))
+                .collect::<Vec<String>>()
+                .join("\n");
+            commit_message += &format!("\n\n{}", footers);
         }
+        commit_message
     }
 
     pub fn commit(
         &self,
         commit_type: CommitType,
         message: &str,
+        options: &CommitOptions,
         add: bool,
         allow_empty: bool,
         run_ci: bool,
     ) -> Result<(), Error> {
-        let commit_message = self.make_commit_message(commit_type, message, run_ci);
+        options.validate()?;
+        let commit_message = self.make_commit_message(commit_type, message, run_ci, options);
+        self.commit_with_message(&commit_message, add, allow_empty)
+    }
+
+    pub fn commit_with_message(
+        &self,
+        commit_message: &str,
+        add: bool,
+        allow_empty: bool,
+    ) -> Result<(), Error> {
         if add {
             let exit_code = Command::new("git")
                 .arg("add")
@@ -107,7 +140,7 @@ impl Branch {
                 .arg("commit")
                 .arg("--allow-empty")
                 .arg("-m")
-                .arg(&commit_message)
+                .arg(commit_message)
                 .status()
                 .map_err(|_| Error::Git)?
                 .code()
@@ -116,7 +149,7 @@ impl Branch {
             Command::new("git")
                 .arg("commit")
                 .arg("-m")
-                .arg(&commit_message)
+                .arg(commit_message)
                 .status()
                 .map_err(|_| Error::Git)?
                 .code()
@@ -129,85 +162,127 @@ impl Branch {
         }
     }
 
+    /// Checks that a hand written header keeps the shape `make_commit_message`
+    /// produces, including this branch's code or release version.
+    pub fn validate_commit_header(&self, header: &str) -> Result<(), Error> {
+        let message = CommitMessage::parse(header)?;
+        let identifier = (!self.is_special).then(|| self.branch_code.clone());
+        let (code, version) = if self.is_release() {
+            (None, identifier)
+        } else {
+            (identifier, None)
+        };
+        if message.code != code || message.version != version {
+            return Err(Error::CommitHeader(header.to_string()));
+        }
+        Ok(())
+    }
+
     pub fn new() -> Result<Self, Error> {
         let raw_name = Self::get_raw_name()?;
         let (branch_type, branch_code, branch_title, is_special) = Self::parse_name(&raw_name)?;
-        return Ok(Branch {
+        Ok(Branch {
             branch_code,
             branch_title,
             branch_type,
             is_special,
-        });
+        })
     }
 
     pub fn from(name: &str) -> Result<Self, Error> {
-        let (branch_type, branch_code, branch_title, is_special) = Self::parse_name(&name)?;
-        return Ok(Branch {
+        let (branch_type, branch_code, branch_title, is_special) = Self::parse_name(name)?;
+        Ok(Branch {
             branch_code,
             branch_title,
             branch_type,
             is_special,
-        });
+        })
     }
 
     pub fn code(&self) -> &str {
         &self.branch_code
     }
 
+    pub fn title(&self) -> &str {
+        &self.branch_title
+    }
+
+    pub fn branch_type(&self) -> &str {
+        &self.branch_type
+    }
+
+    pub fn is_special(&self) -> bool {
+        self.is_special
+    }
+
+    /// Release branches are named by version, which `code` returns.
+    pub fn is_release(&self) -> bool {
+        !self.is_special && Config::get().branch.is_release_type(&self.branch_type)
+    }
+
     fn parse_name(name: &str) -> Result<(String, String, String, bool), Error> {
+        let config = &Config::get().branch;
         let name = name.trim();
         if !Self::validate_name(name) {
             return Err(Error::NameFormat);
         }
-        if Self::SPECIAL_NAMES.contains(&name) {
+        if config.is_special(name) {
             return Ok((String::from(""), String::from(""), String::from(name), true));
         };
-        let slash_index = name.find('/').ok_or(Error::NameFormat)?;
-        
-        let branch_code_index = if Self::CODE_PREFIX.is_empty() {
-            slash_index + 1
+        if let Some(version) = Self::release_version(name) {
+            return Ok((
+                config.release_type.clone(),
+                String::from(version),
+                String::from(""),
+                false,
+            ));
         }
-        else {
-            name.find(Self::CODE_PREFIX).ok_or(Error::NameFormat)? + Self::CODE_PREFIX.len()
-        };
-        let branch_code_index_end = &name[branch_code_index..].find(|c: char| !(c.is_ascii() && c.is_numeric())).ok_or(Error::NameFormat)? + branch_code_index;
-        let branch_title_index = branch_code_index_end + 1;
+        let slash_index = name.find('/').ok_or(Error::NameFormat)?;
+        let rest = &name[slash_index + 1 + config.code_prefix.len()..];
+        let (branch_code, branch_title) = Self::split_code(rest).ok_or(Error::NameFormat)?;
         let branch_type = String::from(&name[..slash_index]);
-        let branch_code = String::from(&name[branch_code_index..branch_code_index_end]);
-        let branch_title = String::from(&name[branch_title_index..]);
-        return Ok((branch_type, branch_code, branch_title, false));
+        Ok((
+            branch_type,
+            String::from(branch_code),
+            String::from(branch_title),
+            false,
+        ))
     }
 
     fn validate_name(name: &str) -> bool {
+        let config = &Config::get().branch;
         if name.is_empty() {
             return false;
         }
-        if Self::SPECIAL_NAMES.contains(&name) {
+        if config.is_special(name) || Self::release_version(name).is_some() {
             return true;
         };
-        'outer: for valid_type in Self::VALID_TYPES.iter() {
-            if !name.starts_with(&format!("{}/{}", valid_type, Self::CODE_PREFIX)) {
-                continue;
-            };
-            let branch_code_start = valid_type.len() + Self::CODE_PREFIX.len() + 1;
-
-            for (i, c) in name[branch_code_start..].chars().enumerate() {
-                if i == 0 && (c < '0' || c > '9') || i == name.len() - branch_code_start - 1 {
-                    continue 'outer;
-                }
-                if c >= '0' && c <= '9' {
-                    continue;
-                }
-                if c == '_' || c == '-' {
-                    break;
-                }
-            }
-            return true;
-        }
-        return false;
+        config.types.iter().any(|valid_type| {
+            let prefix = format!("{}/{}", valid_type, config.code_prefix);
+            name.strip_prefix(&prefix)
+                .and_then(Self::split_code)
+                .is_some()
+        })
+    }
+
+    /// Splits `<code>_<title>` or `<code>-<title>` using the configured code
+    /// format to find where the code ends.
+    fn split_code(rest: &str) -> Option<(&str, &str)> {
+        let code_len = Config::get().branch.code_format.code_len(rest)?;
+        let (code, rest) = rest.split_at(code_len);
+        let title = rest.strip_prefix(['_', '-'])?;
+        (!title.is_empty()).then_some((code, title))
+    }
+
+    /// Returns the version of a `release/<version>` name.
+    fn release_version(name: &str) -> Option<&str> {
+        let version = name
+            .strip_prefix(Config::get().branch.release_type.as_str())?
+            .strip_prefix('/')?;
+        Version::parse(version).is_ok().then_some(version)
     }
 
-    fn get_raw_name() -> Result<String, Error> {
+    pub fn get_raw_name() -> Result<String, Error> {
         Command::new("git")
             .arg("branch")
             .arg("--show-current")
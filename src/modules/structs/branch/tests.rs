@@ -1,86 +1,84 @@
 use super::*;
+use crate::modules::types::errors::CommitParseError;
 
 #[test]
 fn test_validate_name_01() {
-    assert_eq!(Branch::validate_name("feature/2341_something"), true);
+    assert!(Branch::validate_name("feature/2341_something"));
 }
 
 #[test]
 fn test_validate_name_02() {
-    assert_eq!(Branch::validate_name("hotfix/2341_something"), true);
+    assert!(Branch::validate_name("hotfix/2341_something"));
 }
 
 #[test]
 fn test_validate_name_03() {
-    assert_eq!(Branch::validate_name("feature/something"), false);
+    assert!(!Branch::validate_name("feature/something"));
 }
 
 #[test]
 fn test_validate_name_04() {
-    assert_eq!(Branch::validate_name("hotfix/something"), false);
+    assert!(!Branch::validate_name("hotfix/something"));
 }
 
 #[test]
 fn test_validate_name_05() {
-    assert_eq!(Branch::validate_name("feat/2341_something"), false);
+    assert!(!Branch::validate_name("feat/2341_something"));
 }
 
 #[test]
 fn test_validate_name_06() {
-    assert_eq!(Branch::validate_name("fix/2341_something"), false);
+    assert!(!Branch::validate_name("fix/2341_something"));
 }
 
 #[test]
 fn test_validate_name_07() {
-    assert_eq!(Branch::validate_name("fix/something_2341"), false);
+    assert!(!Branch::validate_name("fix/something_2341"));
 }
 
 #[test]
 fn test_validate_name_08() {
-    assert_eq!(Branch::validate_name("feature/something_2341"), false);
+    assert!(!Branch::validate_name("feature/something_2341"));
 }
 
 #[test]
 fn test_validate_name_09() {
-    assert_eq!(Branch::validate_name("feature//1234_something"), false);
+    assert!(!Branch::validate_name("feature//1234_something"));
 }
 
 #[test]
 fn test_validate_name_10() {
-    assert_eq!(
-        Branch::validate_name("feature/something_something"),
-        false
-    );
+    assert!(!Branch::validate_name("feature/something_something"));
 }
 
 #[test]
 fn test_validate_name_11() {
-    assert_eq!(Branch::validate_name("feature/1234something"), false);
+    assert!(!Branch::validate_name("feature/1234something"));
 }
 
 #[test]
 fn test_validate_name_12() {
-    assert_eq!(Branch::validate_name("feature1234_something"), false);
+    assert!(!Branch::validate_name("feature1234_something"));
 }
 
 #[test]
 fn test_validate_name_13() {
-    assert_eq!(Branch::validate_name("master"), true);
+    assert!(Branch::validate_name("master"));
 }
 
 #[test]
 fn test_validate_name_14() {
-    assert_eq!(Branch::validate_name("main"), true);
+    assert!(Branch::validate_name("main"));
 }
 
 #[test]
 fn test_validate_name_15() {
-    assert_eq!(Branch::validate_name("develop"), true);
+    assert!(Branch::validate_name("develop"));
 }
 
 #[test]
 fn test_validate_name_16() {
-    assert_eq!(Branch::validate_name("dfdevelop"), false);
+    assert!(!Branch::validate_name("dfdevelop"));
 }
 
 #[test]
@@ -127,7 +125,12 @@ fn test_make_commit_name_1() {
         is_special: false,
     };
     assert_eq!(
-        branch.make_commit_message(CommitType::Feat, "this is a commit", false),
+        branch.make_commit_message(
+            CommitType::Feat,
+            "this is a commit",
+            false,
+            &CommitOptions::default()
+        ),
         "feat(#2222): this is a commit"
     );
 }
@@ -141,7 +144,12 @@ fn test_make_commit_name_2() {
         is_special: true,
     };
     assert_eq!(
-        branch.make_commit_message(CommitType::Feat, "this is a commit", false),
+        branch.make_commit_message(
+            CommitType::Feat,
+            "this is a commit",
+            false,
+            &CommitOptions::default()
+        ),
         "feat: this is a commit"
     );
 }
@@ -155,7 +163,12 @@ fn test_make_commit_name_3() {
         is_special: true,
     };
     assert_eq!(
-        branch.make_commit_message(CommitType::Feat, "this is a commit", true),
+        branch.make_commit_message(
+            CommitType::Feat,
+            "this is a commit",
+            true,
+            &CommitOptions::default()
+        ),
         "feat: this is a commit (run_ci)"
     );
 }
@@ -169,7 +182,157 @@ fn test_make_commit_name_4() {
         is_special: false,
     };
     assert_eq!(
-        branch.make_commit_message(CommitType::Feat, "this is a commit", true),
+        branch.make_commit_message(
+            CommitType::Feat,
+            "this is a commit",
+            true,
+            &CommitOptions::default()
+        ),
         "feat(#2222): this is a commit (run_ci)"
     );
 }
+
+#[test]
+fn test_make_commit_name_5() {
+    let branch = Branch {
+        branch_code: "2222".to_string(),
+        branch_title: "something".to_string(),
+        branch_type: "feature".to_string(),
+        is_special: false,
+    };
+    let options = CommitOptions {
+        scope: Some("api".to_string()),
+        breaking: true,
+        ..CommitOptions::default()
+    };
+    assert_eq!(
+        branch.make_commit_message(CommitType::Feat, "this is a commit", false, &options),
+        "feat(api,#2222)!: this is a commit"
+    );
+}
+
+#[test]
+fn test_make_commit_name_6() {
+    let branch = Branch {
+        branch_code: "".to_string(),
+        branch_title: "master".to_string(),
+        branch_type: "".to_string(),
+        is_special: true,
+    };
+    let options = CommitOptions {
+        scope: Some("api".to_string()),
+        body: vec![
+            "first paragraph".to_string(),
+            "second paragraph".to_string(),
+        ],
+        footers: vec![
+            ("BREAKING CHANGE".to_string(), "drops v1".to_string()),
+            ("Refs".to_string(), "#12".to_string()),
+        ],
+        ..CommitOptions::default()
+    };
+    assert_eq!(
+        branch.make_commit_message(CommitType::Fix, "this is a commit", true, &options),
+        "fix(api)!: this is a commit (run_ci)\n\nfirst paragraph\n\nsecond paragraph\n\nBREAKING CHANGE: drops v1\nRefs: #12"
+    );
+}
+
+#[test]
+fn test_validate_commit_header() {
+    let branch = Branch {
+        branch_code: "2222".to_string(),
+        branch_title: "something".to_string(),
+        branch_type: "feature".to_string(),
+        is_special: false,
+    };
+    assert_eq!(
+        branch.validate_commit_header("feat(#2222): a commit"),
+        Ok(())
+    );
+    assert_eq!(
+        branch.validate_commit_header("feat(api,#2222)!: a commit"),
+        Ok(())
+    );
+    assert_eq!(
+        branch.validate_commit_header("feat(#2223): a commit"),
+        Err(Error::CommitHeader("feat(#2223): a commit".to_string()))
+    );
+    assert_eq!(
+        branch.validate_commit_header("feat(api): a commit"),
+        Err(Error::CommitHeader("feat(api): a commit".to_string()))
+    );
+    assert_eq!(
+        branch.validate_commit_header("feat(#2222): "),
+        Err(Error::CommitParse(CommitParseError::EmptySubject))
+    );
+    assert_eq!(
+        branch.validate_commit_header("feat(#2222 a commit"),
+        Err(Error::CommitParse(CommitParseError::UnclosedScope))
+    );
+}
+
+#[test]
+fn test_release_name() -> Result<(), Error> {
+    assert!(Branch::validate_name("release/1.4.0"));
+    assert!(Branch::validate_name("release/2.0.0-rc.1"));
+    assert!(!Branch::validate_name("release/1.4"));
+    assert!(!Branch::validate_name("release/1.4.0_title"));
+    assert_eq!(
+        Branch::parse_name("release/1.4.0")?,
+        (
+            String::from("release"),
+            String::from("1.4.0"),
+            String::from(""),
+            false
+        )
+    );
+    assert_eq!(
+        Branch::make_raw_name("release", "1.4.0", ""),
+        Ok("release/1.4.0".to_string())
+    );
+    assert_eq!(
+        Branch::make_raw_name("release", "1.4", ""),
+        Err(Error::Version("1.4".to_string()))
+    );
+    assert_eq!(
+        Branch::make_raw_name("release", "1.4.0", "title"),
+        Err(Error::NameFormat)
+    );
+    assert_eq!(
+        Branch::make_raw_name("feature", "12", ""),
+        Err(Error::NameFormat)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_release_commit_message() -> Result<(), Error> {
+    let branch = Branch::from("release/1.4.0")?;
+    assert!(branch.is_release());
+    assert_eq!(
+        branch.make_commit_message(
+            CommitType::Feat,
+            "this is a commit",
+            false,
+            &CommitOptions::default()
+        ),
+        "feat(1.4.0): this is a commit"
+    );
+    let options = CommitOptions {
+        scope: Some("api".to_string()),
+        ..CommitOptions::default()
+    };
+    assert_eq!(
+        branch.make_commit_message(CommitType::Fix, "this is a commit", false, &options),
+        "fix(api,1.4.0): this is a commit"
+    );
+    assert_eq!(
+        branch.validate_commit_header("fix(1.4.0): a commit"),
+        Ok(())
+    );
+    assert_eq!(
+        branch.validate_commit_header("fix(#12): a commit"),
+        Err(Error::CommitHeader("fix(#12): a commit".to_string()))
+    );
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::modules::{helpers::git_config, types::errors::Error};
+
+/// What `new` knew about a branch when it created it, kept in the
+/// `branch.<name>.sam-*` git config entries of the repository.
+#[derive(PartialEq, Debug, Default, Clone)]
+pub struct BranchMetadata {
+    pub branch_type: String,
+    pub code: String,
+    /// Branch or commit the branch was created from.
+    pub source: String,
+    /// Seconds since the Unix epoch.
+    pub created: u64,
+    /// Commit the branch started at.
+    pub base: String,
+}
+
+impl BranchMetadata {
+    const TYPE: &'static str = "sam-type";
+    const CODE: &'static str = "sam-code";
+    const SOURCE: &'static str = "sam-source";
+    const CREATED: &'static str = "sam-created";
+    const BASE: &'static str = "sam-base";
+
+    pub fn new(branch_type: &str, code: &str, source: &str, base: &str) -> Self {
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        BranchMetadata {
+            branch_type: branch_type.to_string(),
+            code: code.to_string(),
+            source: source.to_string(),
+            created,
+            base: base.to_string(),
+        }
+    }
+
+    /// Returns the stored metadata of `name`, or `None` for branches that
+    /// were not created by sam.
+    pub fn read(name: &str) -> Result<Option<Self>, Error> {
+        let prefix = format!("branch.{}.", name);
+        let entries = git_config::list(&format!("{}sam-", prefix))?;
+        if entries.is_empty() {
+            return Ok(None);
+        }
+        let mut metadata = BranchMetadata::default();
+        for entry in entries {
+            let value = entry.value;
+            match &entry.key[prefix.len()..] {
+                Self::TYPE => metadata.branch_type = value,
+                Self::CODE => metadata.code = value,
+                Self::SOURCE => metadata.source = value,
+                Self::CREATED => metadata.created = value.parse().unwrap_or_default(),
+                Self::BASE => metadata.base = value,
+                _ => {}
+            }
+        }
+        Ok(Some(metadata))
+    }
+
+    pub fn write(&self, name: &str) -> Result<(), Error> {
+        let created = self.created.to_string();
+        for (key, value) in [
+            (Self::TYPE, &self.branch_type),
+            (Self::CODE, &self.code),
+            (Self::SOURCE, &self.source),
+            (Self::CREATED, &created),
+            (Self::BASE, &self.base),
+        ] {
+            if !value.is_empty() {
+                git_config::set("local", &format!("branch.{}.{}", name, key), value)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests;
+
+use crate::modules::{
+    structs::{branch::Branch, commit_options::CommitOptions, config::Config, version::Version},
+    types::{
+        commit_types::CommitType,
+        errors::{CommitParseError, Error},
+    },
+};
+
+type ScopeParts = (Option<String>, Option<String>, Option<String>);
+
+/// A commit message in the `type(scope,#code)!: subject (run_ci)` shape
+/// written by `Branch::make_commit_message`. Commits on release branches
+/// carry the version instead of the code.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CommitMessage {
+    pub commit_type: CommitType,
+    pub code: Option<String>,
+    pub version: Option<String>,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub subject: String,
+    pub body: Vec<String>,
+    pub trailers: Vec<(String, String)>,
+    pub run_ci: bool,
+}
+
+impl CommitMessage {
+    pub fn parse(raw: &str) -> Result<Self, Error> {
+        let lines = raw
+            .lines()
+            .map(|line| line.trim_end())
+            .collect::<Vec<&str>>();
+        let lines = match lines.iter().rposition(|line| !line.is_empty()) {
+            Some(last) => &lines[..=last],
+            None => return Err(CommitParseError::Empty.into()),
+        };
+        let (header, rest) = lines.split_first().ok_or(CommitParseError::Empty)?;
+        let mut message = Self::parse_header(header)?;
+        if let Some((separator, rest)) = rest.split_first() {
+            if !separator.is_empty() {
+                return Err(CommitParseError::MissingBlankLine.into());
+            }
+            let mut paragraphs = rest
+                .split(|line| line.is_empty())
+                .filter(|paragraph| !paragraph.is_empty())
+                .map(|paragraph| paragraph.to_vec())
+                .collect::<Vec<Vec<&str>>>();
+            if let Some(trailers) = paragraphs
+                .last()
+                .and_then(|last| Self::parse_trailers(last))
+                .filter(|trailers| paragraphs.len() > 1 || Self::is_breaking_footer(trailers))
+            {
+                paragraphs.pop();
+                message.trailers = trailers;
+            }
+            message.body = paragraphs
+                .iter()
+                .map(|paragraph| paragraph.join("\n"))
+                .collect();
+        }
+        message.breaking = message.breaking
+            || message
+                .trailers
+                .iter()
+                .any(|(key, _)| CommitOptions::is_breaking_key(key));
+        Ok(message)
+    }
+
+    fn parse_header(header: &str) -> Result<Self, Error> {
+        if header.trim().is_empty() {
+            return Err(CommitParseError::Empty.into());
+        }
+        let type_end = header
+            .find(['(', '!', ':'])
+            .ok_or(CommitParseError::MissingSeparator)?;
+        let commit_type = &header[..type_end];
+        if commit_type.is_empty() {
+            return Err(CommitParseError::EmptyType.into());
+        }
+        let commit_type = CommitType::parse(commit_type)
+            .map_err(|_| CommitParseError::InvalidType(commit_type.to_string()))?;
+
+        let mut rest = &header[type_end..];
+        let (mut scope, mut code, mut version) = (None, None, None);
+        if let Some(after_paren) = rest.strip_prefix('(') {
+            let scope_end = after_paren
+                .find(')')
+                .ok_or(CommitParseError::UnclosedScope)?;
+            (scope, code, version) = Self::parse_scope(&after_paren[..scope_end])?;
+            rest = &after_paren[scope_end + 1..];
+        }
+        let breaking = rest.starts_with('!');
+        let rest = rest.strip_prefix('!').unwrap_or(rest);
+        // a header ending right after the colon had its trailing space trimmed
+        let subject = match rest {
+            ":" => "",
+            rest => rest
+                .strip_prefix(": ")
+                .ok_or(CommitParseError::MissingSeparator)?,
+        };
+
+        let (subject, run_ci) = if subject == Branch::RUN_CI {
+            ("", true)
+        } else if let Some(subject) = subject.strip_suffix(&format!(" {}", Branch::RUN_CI)) {
+            (subject, true)
+        } else {
+            (subject, false)
+        };
+        if subject.trim().is_empty() && !run_ci {
+            return Err(CommitParseError::EmptySubject.into());
+        }
+        Ok(CommitMessage {
+            commit_type,
+            code,
+            version,
+            scope,
+            breaking,
+            subject: subject.trim().to_string(),
+            body: vec![],
+            trailers: vec![],
+            run_ci,
+        })
+    }
+
+    /// Splits `scope,#code` or `scope,version` into its parts, any of which
+    /// may be missing.
+    fn parse_scope(raw: &str) -> Result<ScopeParts, Error> {
+        let (mut scope, mut code, mut version) = (None, None, None);
+        for part in raw.split(',').map(|part| part.trim()) {
+            if part.is_empty() {
+                return Err(CommitParseError::EmptyScope.into());
+            }
+            if let Some(part_code) = part.strip_prefix('#') {
+                if code.is_some() {
+                    return Err(CommitParseError::MultipleCodes.into());
+                }
+                if !Config::get().branch.code_format.is_valid(part_code) {
+                    return Err(CommitParseError::InvalidCode(part.to_string()).into());
+                }
+                code = Some(part_code.to_string());
+            } else if Version::parse(part).is_ok() {
+                if version.is_some() {
+                    return Err(CommitParseError::InvalidScope(raw.to_string()).into());
+                }
+                version = Some(part.to_string());
+            } else {
+                if scope.is_some() || !CommitOptions::is_valid_scope(part) {
+                    return Err(CommitParseError::InvalidScope(raw.to_string()).into());
+                }
+                scope = Some(part.to_string());
+            }
+        }
+        Ok((scope, code, version))
+    }
+
+    /// A lone paragraph after the header is only read as trailers when it
+    /// holds the breaking change footer `--breaking` writes, so a one line
+    /// body such as `Note: see ticket` stays a body.
+    fn is_breaking_footer(trailers: &[(String, String)]) -> bool {
+        trailers
+            .iter()
+            .any(|(key, _)| CommitOptions::is_breaking_key(key))
+    }
+
+    /// Reads a paragraph as trailers when every line is `Key: value`.
+    fn parse_trailers(paragraph: &[&str]) -> Option<Vec<(String, String)>> {
+        paragraph
+            .iter()
+            .map(|line| {
+                let (key, value) = line.split_once(": ")?;
+                (CommitOptions::is_valid_footer_key(key) && !value.trim().is_empty())
+                    .then(|| (key.to_string(), value.trim().to_string()))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,205 @@
+use super::*;
+
+fn round_trip(
+    branch_name: &str,
+    commit_type: CommitType,
+    subject: &str,
+    run_ci: bool,
+    options: CommitOptions,
+) -> Result<(), Error> {
+    let branch = Branch::from(branch_name)?;
+    let raw = branch.make_commit_message(commit_type.clone(), subject, run_ci, &options);
+    let message = CommitMessage::parse(&raw)?;
+    assert_eq!(message.commit_type, commit_type);
+    let identifier = (!branch.code().is_empty()).then(|| branch.code().to_string());
+    if branch.is_release() {
+        assert_eq!(message.code, None);
+        assert_eq!(message.version, identifier);
+    } else {
+        assert_eq!(message.code, identifier);
+        assert_eq!(message.version, None);
+    }
+    assert_eq!(message.scope, options.scope);
+    assert_eq!(message.breaking, options.is_breaking());
+    assert_eq!(message.subject, subject.trim());
+    assert_eq!(message.body, options.body);
+    assert_eq!(message.trailers, options.footers);
+    assert_eq!(message.run_ci, run_ci);
+    Ok(())
+}
+
+#[test]
+fn test_round_trip_1() -> Result<(), Error> {
+    round_trip(
+        "feature/2222_something",
+        CommitType::Feat,
+        "this is a commit",
+        false,
+        CommitOptions::default(),
+    )
+}
+
+#[test]
+fn test_round_trip_2() -> Result<(), Error> {
+    round_trip(
+        "master",
+        CommitType::Fix,
+        "this is a commit",
+        true,
+        CommitOptions::default(),
+    )
+}
+
+#[test]
+fn test_round_trip_3() -> Result<(), Error> {
+    round_trip(
+        "hotfix/13-fix-build-warnings",
+        CommitType::Perf,
+        " this is a commit ",
+        true,
+        CommitOptions {
+            scope: Some("api".to_string()),
+            breaking: true,
+            ..CommitOptions::default()
+        },
+    )
+}
+
+#[test]
+fn test_round_trip_4() -> Result<(), Error> {
+    round_trip(
+        "feature/2222_something",
+        CommitType::Refactor,
+        "this is a commit",
+        false,
+        CommitOptions {
+            scope: None,
+            breaking: false,
+            body: vec![
+                "first paragraph".to_string(),
+                "second paragraph\nwith two lines".to_string(),
+            ],
+            footers: vec![
+                ("BREAKING CHANGE".to_string(), "drops v1".to_string()),
+                ("Reviewed-by".to_string(), "someone".to_string()),
+            ],
+        },
+    )
+}
+
+#[test]
+fn test_round_trip_5() -> Result<(), Error> {
+    round_trip(
+        "develop",
+        CommitType::Docs,
+        "this is a commit",
+        false,
+        CommitOptions {
+            scope: Some("readme".to_string()),
+            body: vec!["Note: this paragraph is\nnot a trailer".to_string()],
+            ..CommitOptions::default()
+        },
+    )
+}
+
+#[test]
+fn test_round_trip_single_line_body() -> Result<(), Error> {
+    round_trip(
+        "feature/2222_something",
+        CommitType::Docs,
+        "this is a commit",
+        false,
+        CommitOptions {
+            body: vec!["Note: see ticket".to_string()],
+            ..CommitOptions::default()
+        },
+    )
+}
+
+#[test]
+fn test_round_trip_breaking_footer_only() -> Result<(), Error> {
+    round_trip(
+        "feature/2222_something",
+        CommitType::Feat,
+        "this is a commit",
+        false,
+        CommitOptions {
+            breaking: true,
+            footers: vec![("BREAKING CHANGE".to_string(), "drops v1".to_string())],
+            ..CommitOptions::default()
+        },
+    )
+}
+
+#[test]
+fn test_round_trip_release() -> Result<(), Error> {
+    round_trip(
+        "release/1.4.0",
+        CommitType::Fix,
+        "this is a commit",
+        false,
+        CommitOptions {
+            scope: Some("api".to_string()),
+            ..CommitOptions::default()
+        },
+    )
+}
+
+#[test]
+fn test_round_trip_run_ci() -> Result<(), Error> {
+    let branch = Branch::from("feature/2222_something")?;
+    let raw = branch.make_commit_message(
+        CommitType::Chore,
+        Branch::RUN_CI,
+        false,
+        &CommitOptions::default(),
+    );
+    let message = CommitMessage::parse(&raw)?;
+    assert_eq!(message.subject, "");
+    assert!(message.run_ci);
+    Ok(())
+}
+
+#[test]
+fn test_parse_errors() {
+    let cases = [
+        ("", CommitParseError::Empty),
+        ("\n\n", CommitParseError::Empty),
+        ("(#12): message", CommitParseError::EmptyType),
+        (
+            "feature(#12): message",
+            CommitParseError::InvalidType("feature".to_string()),
+        ),
+        ("feat(#12: message", CommitParseError::UnclosedScope),
+        ("feat(,#12): message", CommitParseError::EmptyScope),
+        (
+            "feat(a b): message",
+            CommitParseError::InvalidScope("a b".to_string()),
+        ),
+        (
+            "feat(api,ui): message",
+            CommitParseError::InvalidScope("api,ui".to_string()),
+        ),
+        (
+            "feat(#12a): message",
+            CommitParseError::InvalidCode("#12a".to_string()),
+        ),
+        ("feat(#12,#13): message", CommitParseError::MultipleCodes),
+        ("feat message", CommitParseError::MissingSeparator),
+        ("feat(#12) message", CommitParseError::MissingSeparator),
+        ("feat(#12):message", CommitParseError::MissingSeparator),
+        ("feat(#12):  ", CommitParseError::EmptySubject),
+        (
+            "feat(#12): message\nbody",
+            CommitParseError::MissingBlankLine,
+        ),
+    ];
+    for (raw, error) in cases {
+        assert_eq!(
+            CommitMessage::parse(raw),
+            Err(Error::CommitParse(error)),
+            "{}",
+            raw
+        );
+    }
+}
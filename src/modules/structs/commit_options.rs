@@ -0,0 +1,54 @@
+use crate::modules::types::errors::Error;
+
+/// Optional parts of a commit message on top of its type and subject.
+#[derive(Default)]
+pub struct CommitOptions {
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub body: Vec<String>,
+    pub footers: Vec<(String, String)>,
+}
+
+impl CommitOptions {
+    pub const BREAKING_CHANGE: &'static str = "BREAKING CHANGE";
+    pub const BREAKING_CHANGE_ALIAS: &'static str = "BREAKING-CHANGE";
+
+    pub fn is_breaking(&self) -> bool {
+        self.breaking
+            || self
+                .footers
+                .iter()
+                .any(|(key, _)| Self::is_breaking_key(key))
+    }
+
+    pub fn is_breaking_key(key: &str) -> bool {
+        key == Self::BREAKING_CHANGE || key == Self::BREAKING_CHANGE_ALIAS
+    }
+
+    /// A footer key is a single token, or `BREAKING CHANGE`.
+    pub fn is_valid_footer_key(key: &str) -> bool {
+        Self::is_breaking_key(key)
+            || (!key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+    }
+
+    pub fn is_valid_scope(scope: &str) -> bool {
+        !scope.is_empty()
+            && scope
+                .chars()
+                .all(|c| !c.is_whitespace() && !"(),:!#".contains(c))
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if let Some(scope) = &self.scope {
+            if !Self::is_valid_scope(scope) {
+                return Err(Error::CommitScope(scope.to_string()));
+            }
+        }
+        for (key, value) in &self.footers {
+            if !Self::is_valid_footer_key(key) || value.trim().is_empty() || value.contains('\n') {
+                return Err(Error::CommitFooter(format!("{}={}", key, value)));
+            }
+        }
+        Ok(())
+    }
+}
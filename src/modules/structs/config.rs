@@ -0,0 +1,453 @@
+#[cfg(test)]
+mod tests;
+
+use std::{collections::BTreeMap, fs, path::PathBuf, sync::OnceLock};
+
+use serde::{Deserialize, Serialize};
+use toml::{Table, Value};
+
+use crate::modules::{
+    helpers::{
+        git_config::{self, GitConfigEntry},
+        repo::get_root,
+    },
+    types::{
+        code_format::CodeFormat, errors::Error, merge_strategy::MergeStrategy,
+        sync_strategy::SyncStrategy,
+    },
+};
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Config {
+    pub branch: BranchConfig,
+    pub commit: CommitConfig,
+    pub changelog: ChangelogConfig,
+    pub release: ReleaseConfig,
+    pub finish: FinishConfig,
+    pub sync: SyncConfig,
+    pub new: NewConfig,
+    pub remote: RemoteConfig,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct BranchConfig {
+    pub types: Vec<String>,
+    pub special_names: Vec<String>,
+    pub code_prefix: String,
+    /// `digits`, `jira` or `github`.
+    pub code_format: CodeFormat,
+    pub base: BTreeMap<String, String>,
+    /// Branch releases are cut from, where hotfixes are merged first.
+    pub production: String,
+    /// Branch finished hotfixes are merged back into.
+    pub develop: String,
+    /// Branch type finished with the hotfix flow.
+    pub hotfix_type: String,
+    /// Branch type named `<type>/<version>` instead of by ticket code.
+    pub release_type: String,
+}
+
+impl Default for BranchConfig {
+    fn default() -> Self {
+        BranchConfig {
+            types: vec!["feature".to_string(), "hotfix".to_string()],
+            special_names: vec![
+                "develop".to_string(),
+                "main".to_string(),
+                "master".to_string(),
+            ],
+            code_prefix: "".to_string(),
+            code_format: CodeFormat::default(),
+            base: BTreeMap::from([
+                ("feature".to_string(), "develop".to_string()),
+                ("hotfix".to_string(), "master".to_string()),
+                ("release".to_string(), "develop".to_string()),
+            ]),
+            production: "master".to_string(),
+            develop: "develop".to_string(),
+            hotfix_type: "hotfix".to_string(),
+            release_type: "release".to_string(),
+        }
+    }
+}
+
+impl BranchConfig {
+    pub fn is_valid_type(&self, branch_type: &str) -> bool {
+        self.types
+            .iter()
+            .any(|valid_type| valid_type == branch_type)
+    }
+
+    pub fn is_special(&self, name: &str) -> bool {
+        self.special_names.iter().any(|special| special == name)
+    }
+
+    pub fn is_release_type(&self, branch_type: &str) -> bool {
+        self.release_type == branch_type
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct CommitConfig {
+    /// Commit types allowed on top of the Conventional Commits ones.
+    pub types: BTreeMap<String, CustomCommitType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default, Clone)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct CustomCommitType {
+    pub description: String,
+    pub aliases: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ChangelogConfig {
+    /// Link for ticket codes, `{code}` is replaced by the code.
+    pub ticket_url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ReleaseConfig {
+    pub tag_prefix: String,
+    /// Files whose version is rewritten on release, relative to the
+    /// repository root.
+    pub version_files: Vec<String>,
+}
+
+impl Default for ReleaseConfig {
+    fn default() -> Self {
+        ReleaseConfig {
+            tag_prefix: "v".to_string(),
+            version_files: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct FinishConfig {
+    pub strategy: MergeStrategy,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct SyncConfig {
+    pub strategy: SyncStrategy,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct NewConfig {
+    /// Also look for the ticket code in the commits of every branch before
+    /// creating a branch for it.
+    pub check_history: bool,
+}
+
+/// The remotes sam works with. They are the same remote unless the
+/// repository is a fork.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct RemoteConfig {
+    /// Remote holding the base branches, such as `upstream`.
+    pub base: String,
+    /// Remote ticket branches are pushed to, such as `fork`.
+    pub push: String,
+}
+
+impl Default for RemoteConfig {
+    fn default() -> Self {
+        RemoteConfig {
+            base: "origin".to_string(),
+            push: "origin".to_string(),
+        }
+    }
+}
+
+/// A single effective setting together with the layer it was read from.
+#[derive(PartialEq, Debug)]
+pub struct ConfigEntry {
+    pub key: String,
+    pub value: String,
+    pub origin: String,
+}
+
+impl Config {
+    pub const FILE_NAME: &'static str = ".sam.toml";
+    pub const GIT_PREFIX: &'static str = "sam.";
+    const DEFAULT_ORIGIN: &'static str = "default";
+
+    /// Loads the config once. Must be called before any `get` call for the
+    /// repository file and git config to take effect.
+    pub fn init() -> Result<(), Error> {
+        let (config, _) = Self::load()?;
+        let _ = CONFIG.set(config);
+        Ok(())
+    }
+
+    /// Returns the loaded config, or the built-in defaults when `init` was
+    /// never called.
+    pub fn get() -> &'static Config {
+        CONFIG.get_or_init(Config::default)
+    }
+
+    /// Resolves the effective config from the built-in defaults, the
+    /// repository file and `sam.*` git config, in increasing priority.
+    pub fn load() -> Result<(Self, Vec<ConfigEntry>), Error> {
+        Self::resolve(Self::read_file()?, git_config::list(Self::GIT_PREFIX)?)
+    }
+
+    /// Checks that setting `key` to `value` in git config at `scope` gives a
+    /// valid config, before anything is written.
+    pub fn check_git_value(scope: &str, key: &str, value: &str) -> Result<(), Error> {
+        let entry = GitConfigEntry {
+            scope: scope.to_string(),
+            origin: String::new(),
+            key: format!("{}{}", Self::GIT_PREFIX, key),
+            value: value.to_string(),
+        };
+        Self::resolve(Self::read_file()?, vec![entry]).map(|_| ())
+    }
+
+    /// Reads the repository file, tagged with its origin.
+    fn read_file() -> Result<Option<(Table, String)>, Error> {
+        let Some(path) = Self::find_file() else {
+            return Ok(None);
+        };
+        let content = fs::read_to_string(&path)
+            .map_err(|_| Error::Config(format!("could not read {}", path.display())))?;
+        Ok(Some((
+            Self::parse_table(&content)?,
+            format!("file:{}", path.display()),
+        )))
+    }
+
+    /// Layers the parsed repository file, tagged with its origin, and then
+    /// the `sam.*` git config entries over the defaults. A key set in both
+    /// takes the git config value.
+    fn resolve(
+        file: Option<(Table, String)>,
+        git_entries: Vec<GitConfigEntry>,
+    ) -> Result<(Self, Vec<ConfigEntry>), Error> {
+        let mut table = Self::default_table();
+        let mut origins = flatten_table(&table)
+            .into_iter()
+            .map(|(key, _)| (key, Self::DEFAULT_ORIGIN.to_string()))
+            .collect::<BTreeMap<String, String>>();
+
+        if let Some((file_table, origin)) = file {
+            for (key, _) in flatten_table(&file_table) {
+                origins.insert(key, origin.clone());
+            }
+            merge_table(&mut table, file_table);
+            Self::from_table(table.clone())?;
+        }
+
+        // each git config value is checked on its own so an error names it
+        let file_table = table.clone();
+        for entry in git_entries {
+            let key = &entry.key[Self::GIT_PREFIX.len()..];
+            if !Self::is_valid_key(key) {
+                continue;
+            }
+            let invalid = |message: String| {
+                Error::GitConfigValue(entry.key.clone(), entry.scope.clone(), message)
+            };
+            let path = key.split('.').collect::<Vec<&str>>();
+            let current = get_path(&table, &path)
+                .cloned()
+                .or_else(|| Self::template_value(key));
+            let value = coerce_value(current.as_ref(), &entry.value)
+                .ok_or_else(|| invalid(format!("{} has the wrong type", entry.value)))?;
+            let mut checked = file_table.clone();
+            set_path(&mut checked, &path, value.clone());
+            Self::from_table(checked).map_err(|error| match error {
+                Error::Config(message) => invalid(message),
+                error => error,
+            })?;
+            set_path(&mut table, &path, value);
+            origins.insert(key.to_string(), format!("{} {}", entry.scope, entry.origin));
+        }
+
+        let entries = flatten_table(&table)
+            .into_iter()
+            .map(|(key, value)| ConfigEntry {
+                origin: origins.get(&key).cloned().unwrap_or_default(),
+                value: display_value(&value),
+                key,
+            })
+            .collect();
+        Ok((Self::from_table(table)?, entries))
+    }
+
+    /// Strips the optional `sam.` namespace from a key given by the user.
+    pub fn normalize_key(key: &str) -> &str {
+        key.strip_prefix(Self::GIT_PREFIX).unwrap_or(key)
+    }
+
+    pub fn is_valid_key(key: &str) -> bool {
+        Self::template_value(key).is_some()
+    }
+
+    /// Sections whose keys are chosen by the user instead of by sam, with
+    /// the default value of a single entry.
+    fn map_sections() -> Vec<(&'static str, Value)> {
+        vec![
+            ("branch.base", Value::String("".to_string())),
+            (
+                "commit.types",
+                Value::try_from(CustomCommitType::default()).unwrap_or(Value::Table(Table::new())),
+            ),
+        ]
+    }
+
+    /// Returns the default value of `key`, which also tells the type its
+    /// value is read as. Keys of map sections get the entry default.
+    fn template_value(key: &str) -> Option<Value> {
+        if let Some((_, value)) = flatten_table(&Self::default_table())
+            .into_iter()
+            .find(|(default_key, _)| default_key == key)
+        {
+            return Some(value);
+        }
+        Self::map_sections()
+            .into_iter()
+            .find_map(|(section, template)| {
+                let rest = key.strip_prefix(section)?.strip_prefix('.')?;
+                match template {
+                    Value::Table(fields) => {
+                        let (name, field) = rest.split_once('.')?;
+                        if name.is_empty() {
+                            return None;
+                        }
+                        flatten_table(&fields)
+                            .into_iter()
+                            .find(|(field_key, _)| field_key == field)
+                            .map(|(_, value)| value)
+                    }
+                    value => (!rest.is_empty() && !rest.contains('.')).then_some(value),
+                }
+            })
+    }
+
+    fn default_table() -> Table {
+        match Value::try_from(Config::default()) {
+            Ok(Value::Table(table)) => table,
+            _ => Table::new(),
+        }
+    }
+
+    fn parse_table(content: &str) -> Result<Table, Error> {
+        content
+            .parse::<Table>()
+            .map_err(|error| Error::Config(error.message().to_string()))
+    }
+
+    fn from_table(table: Table) -> Result<Self, Error> {
+        Value::Table(table)
+            .try_into()
+            .map_err(|error: toml::de::Error| Error::Config(error.message().to_string()))
+    }
+
+    fn find_file() -> Option<PathBuf> {
+        let path = get_root().ok()?.join(Self::FILE_NAME);
+        path.is_file().then_some(path)
+    }
+}
+
+fn merge_table(base: &mut Table, overlay: Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(base_table)), Value::Table(overlay_table)) => {
+                merge_table(base_table, overlay_table)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Lists every leaf of `table` under its dotted key.
+fn flatten_table(table: &Table) -> Vec<(String, Value)> {
+    let mut entries = vec![];
+    for (key, value) in table {
+        match value {
+            Value::Table(inner) => {
+                for (inner_key, inner_value) in flatten_table(inner) {
+                    entries.push((format!("{}.{}", key, inner_key), inner_value));
+                }
+            }
+            value => entries.push((key.clone(), value.clone())),
+        }
+    }
+    entries
+}
+
+fn get_path<'a>(table: &'a Table, path: &[&str]) -> Option<&'a Value> {
+    let (last, parents) = path.split_last()?;
+    let mut table = table;
+    for key in parents {
+        table = table.get(*key)?.as_table()?;
+    }
+    table.get(*last)
+}
+
+fn set_path(table: &mut Table, path: &[&str], value: Value) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut table = table;
+    for key in parents {
+        let entry = table
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Table(Table::new()));
+        if !entry.is_table() {
+            *entry = Value::Table(Table::new());
+        }
+        let Value::Table(inner) = entry else {
+            return;
+        };
+        table = inner;
+    }
+    table.insert(last.to_string(), value);
+}
+
+/// Converts a git config string into the type of the value it replaces.
+/// Lists are written comma separated.
+fn coerce_value(current: Option<&Value>, raw: &str) -> Option<Value> {
+    match current {
+        Some(Value::Array(_)) => Some(Value::Array(
+            raw.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| Value::String(item.to_string()))
+                .collect(),
+        )),
+        Some(Value::Boolean(_)) => match raw.to_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Some(Value::Boolean(true)),
+            "false" | "no" | "off" | "0" => Some(Value::Boolean(false)),
+            _ => None,
+        },
+        Some(Value::Integer(_)) => raw.parse().ok().map(Value::Integer),
+        _ => Some(Value::String(raw.to_string())),
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<String>>()
+            .join(","),
+        value => value.to_string(),
+    }
+}
@@ -0,0 +1,166 @@
+use super::*;
+
+/// Parses the content of a `.sam.toml` file on top of the defaults.
+fn parse(content: &str) -> Result<Config, Error> {
+    let file = (Config::parse_table(content)?, "file:.sam.toml".to_string());
+    Config::resolve(Some(file), vec![]).map(|(config, _)| config)
+}
+
+fn git_entry(key: &str, value: &str) -> GitConfigEntry {
+    GitConfigEntry {
+        scope: "local".to_string(),
+        origin: "file:.git/config".to_string(),
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn test_parse_empty() -> Result<(), Error> {
+    assert_eq!(parse("")?, Config::default());
+    Ok(())
+}
+
+#[test]
+fn test_parse_partial() -> Result<(), Error> {
+    let config = parse(
+        r#"
+        [branch]
+        types = ["feature", "bugfix"]
+        code-prefix = "T"
+        "#,
+    )?;
+    assert_eq!(config.branch.types, vec!["feature", "bugfix"]);
+    assert_eq!(config.branch.code_prefix, "T");
+    assert_eq!(
+        config.branch.special_names,
+        BranchConfig::default().special_names
+    );
+    assert_eq!(config.remote, RemoteConfig::default());
+    Ok(())
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(matches!(
+        parse("[branch]\ntypes = \"feature\""),
+        Err(Error::Config(_))
+    ));
+}
+
+#[test]
+fn test_parse_base() -> Result<(), Error> {
+    let config = parse(
+        r#"
+        [branch.base]
+        feature = "main"
+        "#,
+    )?;
+    assert_eq!(config.branch.base.get("feature"), Some(&"main".to_string()));
+    assert_eq!(
+        config.branch.base.get("hotfix"),
+        Some(&"master".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_is_valid_key() {
+    assert!(Config::is_valid_key("branch.types"));
+    assert!(Config::is_valid_key("branch.base.bugfix"));
+    assert!(!Config::is_valid_key("branch.base"));
+    assert!(!Config::is_valid_key("branch.base.bugfix.name"));
+    assert!(!Config::is_valid_key("branch.unknown"));
+    assert!(Config::is_valid_key("commit.types.deps.aliases"));
+    assert!(!Config::is_valid_key("commit.types.deps"));
+    assert!(!Config::is_valid_key("commit.types.deps.unknown"));
+}
+
+#[test]
+fn test_coerce_value() {
+    assert_eq!(
+        coerce_value(Some(&Value::Array(vec![])), "feature, bugfix,"),
+        Some(Value::Array(vec![
+            Value::String("feature".to_string()),
+            Value::String("bugfix".to_string())
+        ]))
+    );
+    assert_eq!(
+        coerce_value(Some(&Value::Boolean(false)), "yes"),
+        Some(Value::Boolean(true))
+    );
+    assert_eq!(coerce_value(Some(&Value::Boolean(false)), "maybe"), None);
+    assert_eq!(
+        coerce_value(None, "develop"),
+        Some(Value::String("develop".to_string()))
+    );
+}
+
+#[test]
+fn test_parse_commit_types() -> Result<(), Error> {
+    let config = parse(
+        r#"
+        [commit.types.deps]
+        description = "Dependency updates"
+        aliases = ["dep", "bump"]
+        "#,
+    )?;
+    assert_eq!(
+        config.commit.types.get("deps"),
+        Some(&CustomCommitType {
+            description: "Dependency updates".to_string(),
+            aliases: vec!["dep".to_string(), "bump".to_string()],
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_git_config_overrides_file() -> Result<(), Error> {
+    let file = Config::parse_table(
+        r#"
+        [branch.base]
+        feature = "main"
+        hotfix = "main"
+        "#,
+    )?;
+    let (config, entries) = Config::resolve(
+        Some((file, "file:.sam.toml".to_string())),
+        vec![git_entry("sam.branch.base.feature", "integration")],
+    )?;
+    assert_eq!(
+        config.branch.base.get("feature"),
+        Some(&"integration".to_string())
+    );
+    assert_eq!(config.branch.base.get("hotfix"), Some(&"main".to_string()));
+    let origin = |key: &str| {
+        entries
+            .iter()
+            .find(|entry| entry.key == key)
+            .map(|entry| entry.origin.clone())
+    };
+    assert_eq!(
+        origin("branch.base.feature"),
+        Some("local file:.git/config".to_string())
+    );
+    assert_eq!(
+        origin("branch.base.hotfix"),
+        Some("file:.sam.toml".to_string())
+    );
+    assert_eq!(origin("branch.types"), Some("default".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_git_config_value() {
+    let error = Config::resolve(None, vec![git_entry("sam.finish.strategy", "bogus")]);
+    assert!(matches!(
+        error,
+        Err(Error::GitConfigValue(key, scope, _)) if key == "sam.finish.strategy" && scope == "local"
+    ));
+    let error = Config::resolve(None, vec![git_entry("sam.new.check-history", "maybe")]);
+    assert!(matches!(
+        error,
+        Err(Error::GitConfigValue(key, _, _)) if key == "sam.new.check-history"
+    ));
+}
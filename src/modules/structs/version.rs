@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests;
+
+use std::{cmp::Ordering, fmt};
+
+use crate::modules::types::errors::Error;
+
+/// A semantic version. Build metadata is accepted and dropped.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Option<String>,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Bump {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Bump {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Bump::Patch => "patch",
+            Bump::Minor => "minor",
+            Bump::Major => "major",
+        }
+    }
+}
+
+impl Version {
+    pub fn parse(raw: &str) -> Result<Self, Error> {
+        let version_error = || Error::Version(raw.to_string());
+        let raw_version = raw.split_once('+').map_or(raw, |(version, _)| version);
+        let (core, pre) = match raw_version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (raw_version, None),
+        };
+        let numbers = core
+            .split('.')
+            .map(|number| {
+                let valid = !number.is_empty()
+                    && number.bytes().all(|c| c.is_ascii_digit())
+                    && (number == "0" || !number.starts_with('0'));
+                valid.then(|| number.parse::<u64>().ok()).flatten()
+            })
+            .collect::<Option<Vec<u64>>>()
+            .ok_or_else(version_error)?;
+        let [major, minor, patch] = numbers[..] else {
+            return Err(version_error());
+        };
+        if let Some(pre) = pre {
+            let valid = pre.split('.').all(|identifier| {
+                !identifier.is_empty()
+                    && identifier
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+            if !valid {
+                return Err(version_error());
+            }
+        }
+        Ok(Version {
+            major,
+            minor,
+            patch,
+            pre: pre.map(str::to_string),
+        })
+    }
+
+    /// Returns the next release version, dropping any pre-release part.
+    pub fn bump(&self, bump: Bump) -> Self {
+        let (major, minor, patch) = match bump {
+            Bump::Major => (self.major + 1, 0, 0),
+            Bump::Minor => (self.major, self.minor + 1, 0),
+            Bump::Patch => (self.major, self.minor, self.patch + 1),
+        };
+        Version {
+            major,
+            minor,
+            patch,
+            pre: None,
+        }
+    }
+
+    pub fn with_pre(&self, pre: &str) -> Self {
+        Version {
+            pre: Some(pre.to_string()),
+            ..self.clone()
+        }
+    }
+
+    pub fn is_pre_release(&self) -> bool {
+        self.pre.is_some()
+    }
+
+    /// Returns `N` when the pre-release part is `<id>.N`.
+    pub fn pre_number(&self, id: &str) -> Option<u64> {
+        self.pre
+            .as_ref()?
+            .strip_prefix(id)?
+            .strip_prefix('.')?
+            .parse()
+            .ok()
+    }
+
+    pub fn same_core(&self, other: &Version) -> bool {
+        (self.major, self.minor, self.patch) == (other.major, other.minor, other.patch)
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(pre), Some(other_pre)) => compare_pre(pre, other_pre),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares pre-release parts identifier by identifier, numbers numerically.
+fn compare_pre(pre: &str, other: &str) -> Ordering {
+    let mut identifiers = pre.split('.');
+    let mut other_identifiers = other.split('.');
+    loop {
+        match (identifiers.next(), other_identifiers.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(identifier), Some(other_identifier)) => {
+                let ordering = match (identifier.parse::<u64>(), other_identifier.parse::<u64>()) {
+                    (Ok(number), Ok(other_number)) => number.cmp(&other_number),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => identifier.cmp(other_identifier),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+#[test]
+fn test_parse_1() -> Result<(), Error> {
+    assert_eq!(
+        Version::parse("1.4.0")?,
+        Version {
+            major: 1,
+            minor: 4,
+            patch: 0,
+            pre: None
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_2() -> Result<(), Error> {
+    assert_eq!(
+        Version::parse("1.4.0-rc.2+build.5")?,
+        Version {
+            major: 1,
+            minor: 4,
+            patch: 0,
+            pre: Some("rc.2".to_string())
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_invalid() {
+    for raw in [
+        "1.4",
+        "1.4.0.1",
+        "v1.4.0",
+        "01.4.0",
+        "1.4.x",
+        "1.4.0-",
+        "1.4.0-rc..1",
+    ] {
+        assert_eq!(Version::parse(raw), Err(Error::Version(raw.to_string())));
+    }
+}
+
+#[test]
+fn test_bump() -> Result<(), Error> {
+    let version = Version::parse("1.4.2-rc.1")?;
+    assert_eq!(version.bump(Bump::Patch).to_string(), "1.4.3");
+    assert_eq!(version.bump(Bump::Minor).to_string(), "1.5.0");
+    assert_eq!(version.bump(Bump::Major).to_string(), "2.0.0");
+    Ok(())
+}
+
+#[test]
+fn test_pre_number() -> Result<(), Error> {
+    assert_eq!(Version::parse("1.4.0-rc.3")?.pre_number("rc"), Some(3));
+    assert_eq!(Version::parse("1.4.0-beta.3")?.pre_number("rc"), None);
+    assert_eq!(Version::parse("1.4.0")?.pre_number("rc"), None);
+    Ok(())
+}
+
+#[test]
+fn test_ordering() -> Result<(), Error> {
+    let ordered = [
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.0.1",
+        "1.10.0",
+    ];
+    for pair in ordered.windows(2) {
+        assert!(Version::parse(pair[0])? < Version::parse(pair[1])?);
+    }
+    Ok(())
+}
@@ -0,0 +1,257 @@
+#[cfg(test)]
+mod tests;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml_edit::{DocumentMut, Item, Value};
+
+use crate::modules::types::errors::Error;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum VersionFileKind {
+    Cargo,
+    PackageJson,
+    Pyproject,
+}
+
+/// A file holding the project version, edited in place so the rest of its
+/// formatting is kept.
+#[derive(PartialEq, Debug)]
+pub struct VersionFile {
+    path: PathBuf,
+    kind: VersionFileKind,
+}
+
+impl VersionFile {
+    pub fn new(path: &Path) -> Result<Self, Error> {
+        let kind = match path.file_name().and_then(|name| name.to_str()) {
+            Some("Cargo.toml") => VersionFileKind::Cargo,
+            Some("package.json") => VersionFileKind::PackageJson,
+            Some("pyproject.toml") => VersionFileKind::Pyproject,
+            _ => return Err(Error::VersionFile(path.display().to_string())),
+        };
+        Ok(VersionFile {
+            path: path.to_path_buf(),
+            kind,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn read_version(&self) -> Result<Option<String>, Error> {
+        let content = self.read()?;
+        let version = match self.kind {
+            VersionFileKind::Cargo => {
+                let document = self.parse_toml(&content)?;
+                find_toml_version(&document, &[&["package"], &["workspace", "package"]])
+            }
+            VersionFileKind::Pyproject => {
+                let document = self.parse_toml(&content)?;
+                find_toml_version(&document, &[&["project"], &["tool", "poetry"]])
+            }
+            VersionFileKind::PackageJson => {
+                find_json_version(&content).map(|(start, end)| content[start..end].to_string())
+            }
+        };
+        Ok(version)
+    }
+
+    /// Writes `version` and returns every file that changed. For a Cargo
+    /// workspace this includes the members that do not inherit the version.
+    pub fn write_version(&self, version: &str) -> Result<Vec<PathBuf>, Error> {
+        let content = self.read()?;
+        let mut changed = vec![];
+        let updated = match self.kind {
+            VersionFileKind::Cargo => {
+                for member in self.get_workspace_members(&content)? {
+                    changed.append(&mut member.write_version(version)?);
+                }
+                set_toml_version(
+                    &content,
+                    version,
+                    &[&["package"], &["workspace", "package"]],
+                )
+            }
+            VersionFileKind::Pyproject => {
+                set_toml_version(&content, version, &[&["project"], &["tool", "poetry"]])
+            }
+            VersionFileKind::PackageJson => set_json_version(&content, version),
+        }
+        .map_err(|_| Error::VersionFile(self.path.display().to_string()))?;
+        if let Some(updated) = updated {
+            if updated != content {
+                fs::write(&self.path, updated)
+                    .map_err(|_| Error::VersionFile(self.path.display().to_string()))?;
+                changed.insert(0, self.path.clone());
+            }
+        }
+        Ok(changed)
+    }
+
+    fn read(&self) -> Result<String, Error> {
+        fs::read_to_string(&self.path)
+            .map_err(|_| Error::VersionFile(self.path.display().to_string()))
+    }
+
+    fn parse_toml(&self, content: &str) -> Result<DocumentMut, Error> {
+        content
+            .parse::<DocumentMut>()
+            .map_err(|_| Error::VersionFile(self.path.display().to_string()))
+    }
+
+    /// Resolves `workspace.members`, expanding a trailing `*`.
+    fn get_workspace_members(&self, content: &str) -> Result<Vec<VersionFile>, Error> {
+        let document = self.parse_toml(content)?;
+        let Some(members) = document
+            .get("workspace")
+            .and_then(|workspace| workspace.get("members"))
+            .and_then(|members| members.as_array())
+        else {
+            return Ok(vec![]);
+        };
+        let root = self.path.parent().unwrap_or(Path::new(""));
+        let mut member_dirs = vec![];
+        for member in members.iter().filter_map(|member| member.as_str()) {
+            match member.strip_suffix('*') {
+                Some(parent) => {
+                    let entries = fs::read_dir(root.join(parent))
+                        .map_err(|_| Error::VersionFile(member.to_string()))?;
+                    let mut dirs = entries
+                        .filter_map(|entry| Some(entry.ok()?.path()))
+                        .filter(|path| path.is_dir())
+                        .collect::<Vec<PathBuf>>();
+                    dirs.sort();
+                    member_dirs.append(&mut dirs);
+                }
+                None => member_dirs.push(root.join(member)),
+            }
+        }
+        Ok(member_dirs
+            .into_iter()
+            .map(|dir| dir.join("Cargo.toml"))
+            .filter(|path| path.is_file() && path != &self.path)
+            .map(|path| VersionFile {
+                path,
+                kind: VersionFileKind::Cargo,
+            })
+            .collect())
+    }
+}
+
+/// Returns the version string of the first table in `tables` that has one.
+fn find_toml_version(document: &DocumentMut, tables: &[&[&str]]) -> Option<String> {
+    tables.iter().find_map(|table| {
+        let mut item = document.as_item();
+        for key in table.iter() {
+            item = item.get(key)?;
+        }
+        item.get("version")?.as_str().map(str::to_string)
+    })
+}
+
+/// Replaces the version string of the first table in `tables` that has one.
+/// Returns `None` when no table has a plain version, e.g. when a workspace
+/// member inherits it.
+pub fn set_toml_version(
+    content: &str,
+    version: &str,
+    tables: &[&[&str]],
+) -> Result<Option<String>, Error> {
+    let mut document = content
+        .parse::<DocumentMut>()
+        .map_err(|error| Error::VersionFile(error.to_string()))?;
+    for table in tables {
+        let item = table
+            .iter()
+            .chain(["version"].iter())
+            // `Item::get_mut` would insert the missing tables
+            .try_fold(document.as_item_mut(), |item, key| {
+                item.as_table_like_mut()?.get_mut(key)
+            });
+        let Some(Item::Value(value @ Value::String(_))) = item else {
+            continue;
+        };
+        let decor = value.decor().clone();
+        *value = replace_toml_string(value, version);
+        *value.decor_mut() = decor;
+        return Ok(Some(document.to_string()));
+    }
+    Ok(None)
+}
+
+/// Builds a string value holding `version` that keeps the quoting of
+/// `value`, such as a literal `'0.1.0'`.
+fn replace_toml_string(value: &Value, version: &str) -> Value {
+    let Value::String(current) = value else {
+        return Value::from(version);
+    };
+    current
+        .display_repr()
+        .replacen(current.value().as_str(), version, 1)
+        .parse::<Value>()
+        .ok()
+        .filter(|replaced| replaced.as_str() == Some(version))
+        .unwrap_or_else(|| Value::from(version))
+}
+
+/// Finds the span of the top level `"version"` string value, without its
+/// quotes.
+fn find_json_version(content: &str) -> Option<(usize, usize)> {
+    let bytes = content.as_bytes();
+    let mut depth = 0;
+    let mut index = 0;
+    let mut expect_key = false;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'{' => {
+                depth += 1;
+                expect_key = depth == 1;
+            }
+            b'[' => depth += 1,
+            b'}' | b']' => depth -= 1,
+            b',' => expect_key = depth == 1,
+            b'"' => {
+                let end = find_string_end(bytes, index)?;
+                let is_version_key = expect_key && &content[index + 1..end] == "version";
+                expect_key = false;
+                index = end;
+                if is_version_key {
+                    let rest = content[end + 1..].trim_start();
+                    let rest = rest.strip_prefix(':')?.trim_start();
+                    let start = content.len() - rest.len();
+                    if !rest.starts_with('"') {
+                        return None;
+                    }
+                    let value_end = find_string_end(bytes, start)?;
+                    return Some((start + 1, value_end));
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Returns the index of the quote closing the string opened at `start`.
+fn find_string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'"' => return Some(index),
+            _ => index += 1,
+        }
+    }
+    None
+}
+
+pub fn set_json_version(content: &str, version: &str) -> Result<Option<String>, Error> {
+    Ok(find_json_version(content)
+        .map(|(start, end)| format!("{}{}{}", &content[..start], version, &content[end..])))
+}
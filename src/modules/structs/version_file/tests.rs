@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn test_set_cargo_version() -> Result<(), Error> {
+    let content = "[package]\nname = \"sam\"\nversion = \"0.2.0\"  # keep me\nedition = \"2021\"\n\n[dependencies]\nclap = { version = \"4.5.9\" }\n";
+    assert_eq!(
+        set_toml_version(content, "0.3.0", &[&["package"], &["workspace", "package"]])?,
+        Some(content.replace("\"0.2.0\"", "\"0.3.0\""))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_set_cargo_workspace_version() -> Result<(), Error> {
+    let content =
+        "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"1.0.0\"\n";
+    assert_eq!(
+        set_toml_version(content, "1.1.0", &[&["package"], &["workspace", "package"]])?,
+        Some(content.replace("1.0.0", "1.1.0"))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_set_cargo_inherited_version() -> Result<(), Error> {
+    let content = "[package]\nname = \"member\"\nversion.workspace = true\n";
+    assert_eq!(
+        set_toml_version(content, "1.1.0", &[&["package"], &["workspace", "package"]])?,
+        None
+    );
+    Ok(())
+}
+
+#[test]
+fn test_set_pyproject_version() -> Result<(), Error> {
+    let content = "[tool.poetry]\nname = \"app\"\nversion = '0.1.0'\n";
+    assert_eq!(
+        set_toml_version(content, "0.2.0", &[&["project"], &["tool", "poetry"]])?,
+        Some("[tool.poetry]\nname = \"app\"\nversion = '0.2.0'\n".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_set_json_version() -> Result<(), Error> {
+    let content = "{\n  \"name\": \"app\",\n  \"dependencies\": { \"version\": \"9.9.9\" },\n  \"scripts\": { \"v\": \"echo \\\"version\\\"\" },\n  \"version\" : \"1.2.3\"\n}\n";
+    assert_eq!(
+        set_json_version(content, "1.3.0")?,
+        Some(content.replace("\"1.2.3\"", "\"1.3.0\""))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_set_json_missing_version() -> Result<(), Error> {
+    assert_eq!(
+        set_json_version("{ \"name\": \"version\" }", "1.3.0")?,
+        None
+    );
+    Ok(())
+}
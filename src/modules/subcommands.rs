@@ -1,5 +1,15 @@
+pub mod changelog;
 pub mod commit;
 pub mod checkout;
+pub mod config;
+pub mod delete;
+pub mod finish;
+pub mod info;
 pub mod new;
+pub mod log;
 pub mod ls;
-pub mod run_ci;
\ No newline at end of file
+pub mod prune;
+pub mod release;
+pub mod rename;
+pub mod run_ci;
+pub mod sync;
\ No newline at end of file
@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests;
+
+use std::{fs, path::Path, process::Command};
+
+use crate::{
+    modules::{
+        helpers::log::get_log,
+        structs::{commit_message::CommitMessage, commit_options::CommitOptions, config::Config},
+        types::{commit_types::CommitType, errors::Error},
+    },
+    ChangelogArgs,
+};
+
+const HEADER: &str = "# Changelog\n";
+
+pub fn exec(args: &ChangelogArgs) -> Result<(), Error> {
+    let to = match args.range.split_once("..") {
+        Some((_, to)) => to.trim_start_matches('.'),
+        None => args.range.as_str(),
+    };
+    let to = if to.is_empty() { "HEAD" } else { to };
+    let title = match &args.title {
+        Some(title) => title.clone(),
+        None if to == "HEAD" => "Unreleased".to_string(),
+        None => to
+            .strip_prefix('v')
+            .filter(|version| version.starts_with(|c: char| c.is_ascii_digit()))
+            .unwrap_or(to)
+            .to_string(),
+    };
+    let messages = get_log(&args.range, &[])?
+        .into_iter()
+        .filter_map(|entry| CommitMessage::parse(&entry.message).ok())
+        .collect::<Vec<CommitMessage>>();
+    let date = if to == "HEAD" {
+        None
+    } else {
+        Some(get_date(to)?)
+    };
+    let section = make_section(&title, date.as_deref(), &messages);
+    match &args.update {
+        Some(path) => update_file(path, &section),
+        None => {
+            print!("{}", section);
+            Ok(())
+        }
+    }
+}
+
+/// Renders a Keep a Changelog section with breaking changes first and the
+/// other commits grouped by type.
+pub fn make_section(title: &str, date: Option<&str>, messages: &[CommitMessage]) -> String {
+    let mut section = match date {
+        Some(date) => format!("## [{}] - {}\n", title, date),
+        None => format!("## [{}]\n", title),
+    };
+    let messages = messages
+        .iter()
+        .filter(|message| !message.subject.is_empty())
+        .collect::<Vec<&CommitMessage>>();
+
+    let breaking = messages
+        .iter()
+        .filter(|message| message.breaking)
+        .map(|message| {
+            let description = message
+                .trailers
+                .iter()
+                .find(|(key, _)| CommitOptions::is_breaking_key(key))
+                .map(|(_, description)| description.as_str())
+                .unwrap_or(&message.subject);
+            make_entry(message, description)
+        })
+        .collect::<Vec<String>>();
+    if !breaking.is_empty() {
+        section += &format!("\n### BREAKING CHANGES\n\n{}\n", breaking.join("\n"));
+    }
+
+    for (name, description) in CommitType::valid_types() {
+        let entries = messages
+            .iter()
+            .filter(|message| !message.breaking && message.commit_type.as_str() == name)
+            .map(|message| make_entry(message, &message.subject))
+            .collect::<Vec<String>>();
+        if !entries.is_empty() {
+            section += &format!("\n### {}\n\n{}\n", description, entries.join("\n"));
+        }
+    }
+    section
+}
+
+fn make_entry(message: &CommitMessage, text: &str) -> String {
+    let scope = message
+        .scope
+        .as_ref()
+        .map(|scope| format!("**{}:** ", scope))
+        .unwrap_or_default();
+    let ticket_url = &Config::get().changelog.ticket_url;
+    let code = match &message.code {
+        Some(code) if !ticket_url.is_empty() => {
+            format!(" ([#{}]({}))", code, ticket_url.replace("{code}", code))
+        }
+        Some(code) => format!(" (#{})", code),
+        None => "".to_string(),
+    };
+    format!("- {}{}{}", scope, text, code)
+}
+
+/// Inserts `section` above the newest section of a changelog file, creating
+/// the file when it does not exist.
+fn update_file(path: &Path, section: &str) -> Result<(), Error> {
+    let content = if path.exists() {
+        fs::read_to_string(path).map_err(|_| Error::Changelog(path.display().to_string()))?
+    } else {
+        HEADER.to_string()
+    };
+    let index = content
+        .match_indices("## ")
+        .find(|(index, _)| *index == 0 || content[..*index].ends_with('\n'))
+        .map(|(index, _)| index);
+    let content = match index {
+        Some(index) => format!("{}{}\n{}", &content[..index], section, &content[index..]),
+        None => format!("{}\n\n{}", content.trim_end(), section),
+    };
+    fs::write(path, content).map_err(|_| Error::Changelog(path.display().to_string()))
+}
+
+fn get_date(revision: &str) -> Result<String, Error> {
+    let output = Command::new("git")
+        .arg("log")
+        .arg("-1")
+        .arg("--format=%as")
+        .arg(revision)
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let date = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(date.trim().to_string())
+}
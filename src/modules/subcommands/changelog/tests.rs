@@ -0,0 +1,50 @@
+use super::*;
+
+fn message(raw: &str) -> CommitMessage {
+    CommitMessage::parse(raw).expect("valid commit message")
+}
+
+#[test]
+fn test_make_section_1() {
+    let messages = [
+        message("fix(#12): second fix"),
+        message("feat(api,#12): a feature"),
+        message("fix: first fix"),
+        message("chore(#12): (run_ci)"),
+    ];
+    assert_eq!(
+        make_section("1.1.0", Some("2024-01-02"), &messages),
+        "## [1.1.0] - 2024-01-02\n\
+        \n\
+        ### Features\n\
+        \n\
+        - **api:** a feature (#12)\n\
+        \n\
+        ### Bug Fixes\n\
+        \n\
+        - second fix (#12)\n\
+        - first fix\n"
+    );
+}
+
+#[test]
+fn test_make_section_2() {
+    let messages = [
+        message("feat(#12): a feature"),
+        message("feat(#13)!: drop the old api"),
+        message("refactor: rename\n\nBREAKING CHANGE: config keys are renamed"),
+    ];
+    assert_eq!(
+        make_section("Unreleased", None, &messages),
+        "## [Unreleased]\n\
+        \n\
+        ### BREAKING CHANGES\n\
+        \n\
+        - drop the old api (#13)\n\
+        - config keys are renamed\n\
+        \n\
+        ### Features\n\
+        \n\
+        - a feature (#12)\n"
+    );
+}
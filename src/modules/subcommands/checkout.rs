@@ -1,44 +1,135 @@
-use std::process::Command;
+#[cfg(test)]
+mod tests;
 
-use crate::modules::{
-    helpers::branch::{filter_branches_by_code, get_branches},
-    structs::branch::Branch,
-    types::errors::Error,
+use crate::{
+    modules::{
+        helpers::{
+            branch::{checkout_branch, get_branches, resolve_branch},
+            fuzzy,
+            repo::{ref_exists, rev_parse, run_git},
+        },
+        structs::{branch::Branch, config::Config, version::Version},
+        types::errors::Error,
+    },
+    CheckoutArgs,
 };
 
-pub fn exec(input: &str) -> Result<(), Error> {
-    if Branch::SPECIAL_NAMES.contains(&input) {
-        checkout_literal(input)?;
+pub fn exec(args: &CheckoutArgs) -> Result<(), Error> {
+    let input = args.branch_code.as_str();
+    if args.literal || Config::get().branch.is_special(input) || is_full_name(input) {
+        checkout_branch(input)?;
         return Ok(());
     }
-    checkout_with_code(input)?;
+    checkout_with_code(input, args.branch_type.as_deref(), args.pick)?;
     Ok(())
 }
 
-fn checkout_with_code(branch_code: &str) -> Result<(), Error> {
-    if !branch_code.bytes().all(|c| c.is_ascii_digit()) {
-        return Err(Error::BranchCode);
+/// Full branch names, including release branches whatever follows the
+/// slash, are taken as they are.
+fn is_full_name(input: &str) -> bool {
+    Branch::from(input).is_ok()
+        || input
+            .strip_prefix(Config::get().branch.release_type.as_str())
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn checkout_with_code(
+    branch_code: &str,
+    branch_type: Option<&str>,
+    pick: Option<usize>,
+) -> Result<(), Error> {
+    // codes and release versions name branches first; tags, titles and
+    // commit hashes such as an all digit `1234567` are tried after that
+    let code_format = Config::get().branch.code_format;
+    let is_code = code_format.is_valid(&code_format.normalize(branch_code))
+        || Version::parse(branch_code).is_ok();
+    let not_found = if is_code {
+        match resolve_branch(branch_code, branch_type, pick) {
+            Ok(name) => return checkout_branch(&name),
+            Err(error @ Error::BranchNotFoundOnCheckout(_)) => Some(error),
+            Err(error) => return Err(error),
+        }
+    } else {
+        None
     };
-    let branches = get_branches()?;
-    let matches = filter_branches_by_code(&branches, branch_code);
-    if matches.is_empty() {
-        return Err(Error::BranchNotFoundOnCheckout(branch_code.to_string()));
+    if ref_exists(&format!("refs/tags/{}", branch_code))? {
+        return checkout_detached(branch_code);
     }
-    let first_match = &matches[0];
-    checkout_literal(&first_match)?;
+    let result = match not_found {
+        Some(error) => Err(error),
+        None => checkout_with_title(branch_code, branch_type, pick),
+    };
+    match result {
+        Err(Error::BranchNotFoundOnCheckout(_) | Error::NoTitleMatch(_))
+            if is_commit_hash(branch_code) =>
+        {
+            checkout_detached(branch_code)
+        }
+        result => result,
+    }
+}
+
+/// Short hashes need at least 7 characters, the length git abbreviates to.
+fn is_commit_hash(input: &str) -> bool {
+    (7..=40).contains(&input.len())
+        && input.bytes().all(|c| c.is_ascii_hexdigit())
+        && rev_parse(input).is_ok()
+}
+
+fn checkout_detached(target: &str) -> Result<(), Error> {
+    run_git(&["checkout", "--detach", target])?;
+    println!("HEAD is detached at {}", target);
     Ok(())
 }
 
-fn checkout_literal(branch_name: &str) -> Result<(), Error> {
-    let exit_code = Command::new("git")
-        .arg("checkout")
-        .arg(branch_name)
-        .status()
-        .map_err(|_| Error::Git)?
-        .code()
-        .unwrap_or(-1);
-    if exit_code != 0 {
-        return Err(Error::Git);
+fn checkout_with_title(
+    query: &str,
+    branch_type: Option<&str>,
+    pick: Option<usize>,
+) -> Result<(), Error> {
+    let branches = get_branches()?;
+    let candidates = branches
+        .iter()
+        .filter_map(|name| {
+            let branch = Branch::from(name).ok()?;
+            if branch.is_special()
+                || branch_type.is_some_and(|branch_type| branch.branch_type() != branch_type)
+            {
+                return None;
+            }
+            Some((branch.title().to_string(), name.as_str()))
+        })
+        .collect::<Vec<(String, &str)>>();
+    checkout_branch(select_by_title(query, &candidates, pick)?)
+}
+
+/// Ranks the `(title, name)` candidates by how well their title matches
+/// `query`. The best match wins only when no other match scores as high;
+/// `pick` chooses by 1-based position in the ranking instead.
+fn select_by_title<'a>(
+    query: &str,
+    candidates: &[(String, &'a str)],
+    pick: Option<usize>,
+) -> Result<&'a str, Error> {
+    let mut ranked = candidates
+        .iter()
+        .filter_map(|(title, name)| Some((fuzzy::score(query, title)?, *name)))
+        .collect::<Vec<(i64, &str)>>();
+    // best first, ties in name order
+    ranked.sort_by(|(score, name), (other_score, other_name)| {
+        other_score.cmp(score).then(name.cmp(other_name))
+    });
+    match (ranked.as_slice(), pick) {
+        ([], _) => Err(Error::NoTitleMatch(query.to_string())),
+        (ranked, Some(pick)) => ranked
+            .get(pick.wrapping_sub(1))
+            .map(|(_, name)| *name)
+            .ok_or(Error::InvalidPick(pick, ranked.len())),
+        ([(_, name)], None) => Ok(name),
+        ([(best, name), (second, _), ..], None) if best > second => Ok(name),
+        (ranked, None) => Err(Error::AmbiguousTitle(
+            query.to_string(),
+            ranked.iter().map(|(_, name)| name.to_string()).collect(),
+        )),
     }
-    Ok(())
 }
@@ -0,0 +1,80 @@
+use super::*;
+
+fn candidates(names: &[&'static str]) -> Vec<(String, &'static str)> {
+    names
+        .iter()
+        .map(|name| (Branch::from(name).unwrap().title().to_string(), *name))
+        .collect()
+}
+
+#[test]
+fn test_select_by_title_unique() -> Result<(), Error> {
+    let candidates = candidates(&["feature/1_login-timeout", "feature/2_logout-button"]);
+    assert_eq!(
+        select_by_title("timeout", &candidates, None)?,
+        "feature/1_login-timeout"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_select_by_title_best() -> Result<(), Error> {
+    let candidates = candidates(&["feature/1_log-in-out", "feature/2_login-page"]);
+    assert_eq!(
+        select_by_title("login", &candidates, None)?,
+        "feature/2_login-page"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_select_by_title_tie() {
+    let candidates = candidates(&["hotfix/2_login-page", "feature/1_login-page"]);
+    assert!(matches!(
+        select_by_title("login", &candidates, None),
+        Err(Error::AmbiguousTitle(query, matches))
+            if query == "login" && matches == vec!["feature/1_login-page", "hotfix/2_login-page"]
+    ));
+}
+
+#[test]
+fn test_select_by_title_pick() -> Result<(), Error> {
+    let candidates = candidates(&["hotfix/2_login-page", "feature/1_login-page"]);
+    assert_eq!(
+        select_by_title("login", &candidates, Some(2))?,
+        "hotfix/2_login-page"
+    );
+    assert!(matches!(
+        select_by_title("login", &candidates, Some(3)),
+        Err(Error::InvalidPick(3, 2))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_select_by_title_no_match() {
+    let candidates = candidates(&["feature/1_login-page"]);
+    assert!(matches!(
+        select_by_title("zzz", &candidates, None),
+        Err(Error::NoTitleMatch(_))
+    ));
+}
+
+#[test]
+fn test_is_full_name() {
+    assert!(is_full_name("feature/12_login"));
+    assert!(is_full_name("hotfix/3_crash"));
+    assert!(is_full_name("release/1.4.0"));
+    assert!(is_full_name("release/next"));
+    assert!(!is_full_name("release"));
+    assert!(!is_full_name("releases/1.4.0"));
+    assert!(!is_full_name("12"));
+    assert!(!is_full_name("login"));
+    assert!(!is_full_name("bugfix/12_login"));
+}
+
+#[test]
+fn test_is_commit_hash_short() {
+    assert!(!is_commit_hash("be0f"));
+    assert!(!is_commit_hash("be0f9f"));
+}
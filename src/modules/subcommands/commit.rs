@@ -1,6 +1,10 @@
+#[cfg(test)]
+mod tests;
+
 use crate::{
     modules::{
-        structs::branch::Branch,
+        helpers::{editor, repo::get_git_dir},
+        structs::{branch::Branch, commit_options::CommitOptions},
         types::{commit_types::CommitType, errors::Error},
     },
     CommitArgs,
@@ -8,19 +12,108 @@ use crate::{
 
 pub fn exec(args: &CommitArgs) -> Result<(), Error> {
     let branch = Branch::new()?;
-    let commit_type = match &args.commit_type[..] {
-        "feat" => CommitType::Feat,
-        "chore" => CommitType::Chore,
-        "style" => CommitType::Style,
-        "fix" => CommitType::Fix,
-        _ => return Err(Error::CommitType),
+    let commit_type = CommitType::parse(&args.commit_type)?;
+    let options = make_options(args)?;
+    if let Some(message) = &args.message {
+        return branch.commit(
+            commit_type,
+            message,
+            &options,
+            !args.no_add,
+            args.empty,
+            args.run_ci,
+        );
+    }
+    options.validate()?;
+    let commit_message = edit_message(&branch, commit_type, &options, args.run_ci)?;
+    branch.commit_with_message(&commit_message, !args.no_add, args.empty)
+}
+
+const TEMPLATE_FILE: &str = "SAM_COMMIT_EDITMSG";
+/// Everything from this line on is dropped from the edited message, the same
+/// way `git commit --cleanup=scissors` does it.
+const SCISSORS: &str = "# ------------------------ >8 ------------------------";
+
+fn edit_message(
+    branch: &Branch,
+    commit_type: CommitType,
+    options: &CommitOptions,
+    run_ci: bool,
+) -> Result<String, Error> {
+    let path = get_git_dir()?.join(TEMPLATE_FILE);
+    let message = branch.make_commit_message(commit_type, "", false, options);
+    let edited = editor::edit(&path, &make_template(&message))?;
+    parse_edited_message(branch, &edited, run_ci)
+}
+
+fn parse_edited_message(branch: &Branch, edited: &str, run_ci: bool) -> Result<String, Error> {
+    let mut lines = edited
+        .lines()
+        .take_while(|line| *line != SCISSORS)
+        .map(|line| line.trim_end())
+        .skip_while(|line| line.is_empty())
+        .collect::<Vec<&str>>();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    let Some((header, rest)) = lines.split_first() else {
+        return Err(Error::EmptyCommitMessage);
     };
-    branch.commit(
-        commit_type,
-        &args.message,
-        !args.no_add,
-        args.empty,
-        args.run_ci,
-    )?;
-    Ok(())
+    // the pre-filled header saved as is has no description
+    if header
+        .split_once(':')
+        .is_some_and(|(_, description)| description.trim().is_empty())
+    {
+        return Err(Error::EmptyCommitMessage);
+    }
+    branch.validate_commit_header(header)?;
+    let header = if run_ci && !header.ends_with(Branch::RUN_CI) {
+        format!("{} {}", header, Branch::RUN_CI)
+    } else {
+        header.to_string()
+    };
+    Ok([vec![header.as_str()], rest.to_vec()].concat().join("\n"))
+}
+
+fn make_template(message: &str) -> String {
+    let mut template = format!(
+        "{}\n\
+        \n\
+        {}\n\
+        # Do not modify or remove the line above, everything below it is\n\
+        # ignored. Write the commit message after the header on the first line.\n\
+        # A body can follow after an empty line. A header without a description\n\
+        # aborts the commit.\n\
+        #\n\
+        # Allowed types:\n",
+        message, SCISSORS
+    );
+    for (name, description) in CommitType::valid_types() {
+        template += &format!("#   {:<10} {}\n", name, description);
+    }
+    template
+}
+
+fn make_options(args: &CommitArgs) -> Result<CommitOptions, Error> {
+    let mut footers = vec![];
+    if let Some(description) = &args.breaking {
+        if !description.trim().is_empty() {
+            footers.push((
+                CommitOptions::BREAKING_CHANGE.to_string(),
+                description.to_string(),
+            ));
+        }
+    }
+    for footer in &args.footer {
+        let (key, value) = footer
+            .split_once('=')
+            .ok_or_else(|| Error::CommitFooter(footer.to_string()))?;
+        footers.push((key.trim().to_string(), value.to_string()));
+    }
+    Ok(CommitOptions {
+        scope: args.scope.clone(),
+        breaking: args.breaking.is_some(),
+        body: args.body.clone(),
+        footers,
+    })
 }
@@ -0,0 +1,57 @@
+use super::*;
+
+fn branch() -> Branch {
+    Branch::from("feature/12_something").unwrap()
+}
+
+#[test]
+fn test_make_template() {
+    let template = make_template("feat(#12): ");
+    let mut lines = template.lines();
+    assert_eq!(lines.next(), Some("feat(#12): "));
+    assert_eq!(lines.next(), Some(""));
+    assert_eq!(lines.next(), Some(SCISSORS));
+    assert!(lines.all(|line| line.starts_with('#')));
+    assert!(template.contains("#   feat"));
+}
+
+#[test]
+fn test_parse_edited_message() -> Result<(), Error> {
+    let edited = format!(
+        "feat(#12): add a thing\n\n#123 follow-up\nmore body\n\n{}\n# ignored\nignored\n",
+        SCISSORS
+    );
+    assert_eq!(
+        parse_edited_message(&branch(), &edited, false)?,
+        "feat(#12): add a thing\n\n#123 follow-up\nmore body"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_edited_message_run_ci() -> Result<(), Error> {
+    let edited = format!("feat(#12): add a thing\n{}\n", SCISSORS);
+    assert_eq!(
+        parse_edited_message(&branch(), &edited, true)?,
+        "feat(#12): add a thing (run_ci)"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_edited_message_unchanged() {
+    let edited = make_template("feat(#12): ");
+    assert!(matches!(
+        parse_edited_message(&branch(), &edited, false),
+        Err(Error::EmptyCommitMessage)
+    ));
+}
+
+#[test]
+fn test_parse_edited_message_empty() {
+    let edited = make_template("");
+    assert!(matches!(
+        parse_edited_message(&branch(), &edited, false),
+        Err(Error::EmptyCommitMessage)
+    ));
+}
@@ -0,0 +1,65 @@
+use crate::{
+    modules::{helpers::git_config, structs::config::Config, types::errors::Error},
+    ConfigAction, ConfigArgs, ConfigScopeArgs,
+};
+
+pub fn exec(args: &ConfigArgs) -> Result<(), Error> {
+    match &args.action {
+        ConfigAction::Get(args) => get(&args.key),
+        ConfigAction::Set(args) => {
+            let key = validate_key(&args.key)?;
+            Config::check_git_value(scope(&args.scope), key, &args.value)?;
+            git_config::set(scope(&args.scope), &git_key(key), &args.value)
+        }
+        ConfigAction::Unset(args) => {
+            let key = validate_key(&args.key)?;
+            git_config::unset(scope(&args.scope), &git_key(key))
+        }
+        ConfigAction::List(args) => list(args.show_origin),
+    }
+}
+
+fn get(key: &str) -> Result<(), Error> {
+    let key = validate_key(key)?;
+    let (_, entries) = Config::load()?;
+    let entry = entries
+        .iter()
+        .find(|entry| entry.key == key)
+        .ok_or_else(|| Error::ConfigKeyNotSet(key.to_string()))?;
+    println!("{}", entry.value);
+    Ok(())
+}
+
+fn list(show_origin: bool) -> Result<(), Error> {
+    let (_, entries) = Config::load()?;
+    for entry in entries {
+        if show_origin {
+            println!("{}\t{}={}", entry.origin, entry.key, entry.value);
+        } else {
+            println!("{}={}", entry.key, entry.value);
+        }
+    }
+    Ok(())
+}
+
+fn validate_key(key: &str) -> Result<&str, Error> {
+    let key = Config::normalize_key(key);
+    if !Config::is_valid_key(key) {
+        return Err(Error::ConfigKey(key.to_string()));
+    }
+    Ok(key)
+}
+
+fn git_key(key: &str) -> String {
+    format!("{}{}", Config::GIT_PREFIX, key)
+}
+
+fn scope(args: &ConfigScopeArgs) -> &'static str {
+    if args.global {
+        "global"
+    } else if args.system {
+        "system"
+    } else {
+        "local"
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    modules::{
+        helpers::{
+            branch::{filter_branches_by_code, is_merged_into_base, list_refs},
+            remote::get_remotes,
+            repo::run_git,
+        },
+        structs::branch::Branch,
+        types::errors::Error,
+    },
+    DeleteArgs,
+};
+
+const LOCAL_NAMESPACE: &str = "refs/heads/";
+
+struct Target {
+    name: String,
+    /// The remote holding the branch, `None` for a local branch.
+    remote: Option<String>,
+    merged: bool,
+}
+
+impl Target {
+    fn label(&self) -> String {
+        format!(
+            "{}{} ({})",
+            match &self.remote {
+                Some(remote) => format!("{}/", remote),
+                None => "".to_string(),
+            },
+            self.name,
+            if self.merged { "merged" } else { "not merged" }
+        )
+    }
+}
+
+pub fn exec(args: &DeleteArgs) -> Result<(), Error> {
+    let current = Branch::get_raw_name()?;
+    let mut targets = vec![];
+    let mut namespaces = vec![(LOCAL_NAMESPACE.to_string(), None)];
+    for remote in get_remotes()? {
+        namespaces.push((format!("refs/remotes/{}/", remote), Some(remote)));
+    }
+    for (namespace, remote) in namespaces {
+        let names = list_refs(&namespace)?;
+        let mut matches = filter_branches_by_code(&names, &args.branch_code);
+        matches.sort();
+        for name in matches {
+            let branch = Branch::from(name)?;
+            let reference = format!("{}{}", namespace, name);
+            targets.push(Target {
+                merged: is_merged_into_base(&reference, name, branch.branch_type())?,
+                name: name.to_string(),
+                remote: remote.clone(),
+            });
+        }
+    }
+    if targets.is_empty() {
+        return Err(Error::BranchNotFoundOnCheckout(
+            args.branch_code.to_string(),
+        ));
+    }
+    if let Some(target) = targets
+        .iter()
+        .find(|target| target.remote.is_none() && target.name == current)
+    {
+        return Err(Error::CurrentBranch(target.name.clone()));
+    }
+
+    let unmerged = targets
+        .iter()
+        .filter(|target| !target.merged)
+        .map(Target::label)
+        .collect::<Vec<String>>();
+    if !unmerged.is_empty() && !args.force {
+        return Err(Error::Unmerged(unmerged));
+    }
+    println!("Deleting:");
+    for target in &targets {
+        println!("  {}", target.label());
+    }
+    for target in targets {
+        match &target.remote {
+            Some(remote) => run_git(&["push", remote, "--delete", &target.name])?,
+            None => run_git(&["branch", "-D", &target.name])?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,338 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use crate::{
+    modules::{
+        helpers::{
+            branch::{checkout_branch, get_branch_base, is_merged, resolve_branch},
+            remote::{base_remote, get_remotes, push_remote},
+            repo::{is_clean, is_merging, ref_exists, run_git},
+            state::{clear_state, read_state, write_state},
+            tag::{create_annotated_tag, get_last_release_tag, make_tag_name},
+        },
+        structs::{
+            branch::Branch,
+            config::Config,
+            version::{Bump, Version},
+        },
+        types::{errors::Error, merge_strategy::MergeStrategy},
+    },
+    FinishArgs,
+};
+
+const STATE_NAME: &str = "finish";
+
+pub fn exec(args: &FinishArgs) -> Result<(), Error> {
+    if args.continue_flow {
+        return continue_hotfix();
+    }
+    if args.abort {
+        return abort_hotfix();
+    }
+    if read_state(STATE_NAME)?.is_some() {
+        return Err(Error::FlowInProgress(STATE_NAME.to_string()));
+    }
+    if !is_clean()? {
+        return Err(Error::DirtyTree);
+    }
+    let name = match &args.branch_code {
+        Some(branch_code) => resolve_branch(branch_code, args.branch_type.as_deref(), args.pick)?,
+        None => Branch::get_raw_name()?,
+    };
+    let branch = Branch::from(&name)?;
+    if branch.is_special() {
+        return Err(Error::SpecialBranch(name));
+    }
+
+    // a branch that only exists on a remote gets a local copy first
+    if !ref_exists(&format!("refs/heads/{}", name))? {
+        checkout_branch(&name)?;
+    }
+    if branch.branch_type() == Config::get().branch.hotfix_type {
+        return start_hotfix(&branch, &name);
+    }
+    let base = get_branch_base(&name, branch.branch_type())?;
+    let strategy = args.strategy.unwrap_or(Config::get().finish.strategy);
+    // the merge needs a local base to land on
+    if !ref_exists(&format!("refs/heads/{}", base))? {
+        checkout_branch(&base)?;
+    }
+    merge(
+        &name,
+        &base,
+        strategy,
+        &make_merge_message(&branch, &name, &base),
+    )?;
+    // the base is published before the branch it now holds is deleted
+    if ref_exists(&format!("refs/remotes/{}/{}", base_remote(), base))? {
+        run_git(&["push", base_remote(), &base])?;
+    }
+    delete_branch(&name)?;
+    println!("Finished {} into {}", name, base);
+    Ok(())
+}
+
+fn make_merge_message(branch: &Branch, name: &str, base: &str) -> String {
+    format!(
+        "Merge #{} {} ({}) into {}",
+        branch.code(),
+        branch.title().replace(['-', '_'], " "),
+        name,
+        base
+    )
+}
+
+/// Brings `name` into `base` and leaves `base` checked out.
+fn merge(name: &str, base: &str, strategy: MergeStrategy, message: &str) -> Result<(), Error> {
+    for args in make_merge_commands(name, base, strategy, message) {
+        let args = args.iter().map(String::as_str).collect::<Vec<&str>>();
+        if args[0] == "checkout" {
+            run_git(&args)?;
+        } else {
+            run_git(&args).map_err(|_| Error::Merge(name.to_string()))?;
+        }
+    }
+    Ok(())
+}
+
+/// The git commands `merge` runs for `strategy`, in order.
+fn make_merge_commands(
+    name: &str,
+    base: &str,
+    strategy: MergeStrategy,
+    message: &str,
+) -> Vec<Vec<String>> {
+    let commands: Vec<Vec<&str>> = match strategy {
+        MergeStrategy::Merge => vec![
+            vec!["checkout", base],
+            vec!["merge", "--no-ff", "-m", message, name],
+        ],
+        MergeStrategy::Squash => vec![
+            vec!["checkout", base],
+            vec!["merge", "--squash", name],
+            vec!["commit", "-m", message],
+        ],
+        MergeStrategy::Rebase => vec![
+            vec!["rebase", base, name],
+            vec!["checkout", base],
+            vec!["merge", "--ff-only", name],
+        ],
+    };
+    commands
+        .into_iter()
+        .map(|args| args.into_iter().map(str::to_string).collect())
+        .collect()
+}
+
+/// Deletes the branch locally and on the remote when it was pushed.
+fn delete_branch(name: &str) -> Result<(), Error> {
+    // squashed branches do not look merged to `git branch -d`
+    run_git(&["branch", "-D", name])?;
+    let remote = push_remote();
+    if ref_exists(&format!("refs/remotes/{}/{}", remote, name))? {
+        run_git(&["push", remote, "--delete", name])?;
+    }
+    Ok(())
+}
+
+/// The git-flow hotfix finish, in order. The state file records the step
+/// that is running so a conflict can be resumed.
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum HotfixStep {
+    MergeProduction,
+    Tag,
+    MergeDevelop,
+    Push,
+    Delete,
+}
+
+impl HotfixStep {
+    const ALL: [HotfixStep; 5] = [
+        HotfixStep::MergeProduction,
+        HotfixStep::Tag,
+        HotfixStep::MergeDevelop,
+        HotfixStep::Push,
+        HotfixStep::Delete,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            HotfixStep::MergeProduction => "merge-production",
+            HotfixStep::Tag => "tag",
+            HotfixStep::MergeDevelop => "merge-develop",
+            HotfixStep::Push => "push",
+            HotfixStep::Delete => "delete",
+        }
+    }
+
+    fn parse(step: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|known| known.as_str() == step)
+    }
+}
+
+fn start_hotfix(branch: &Branch, name: &str) -> Result<(), Error> {
+    let config = &Config::get().branch;
+    if get_remotes()?.iter().any(|remote| remote == base_remote()) {
+        run_git(&["fetch", base_remote()])?;
+    }
+    let version = match get_last_release_tag(&config.production)? {
+        Some((_, version)) => version.bump(Bump::Patch),
+        None => Version::parse("0.0.1")?,
+    };
+    let state = BTreeMap::from([
+        ("branch".to_string(), name.to_string()),
+        ("production".to_string(), config.production.clone()),
+        ("develop".to_string(), config.develop.clone()),
+        ("tag".to_string(), make_tag_name(&version)),
+        (
+            "message".to_string(),
+            make_merge_message(branch, name, &config.production),
+        ),
+    ]);
+    run_hotfix(state, 0)
+}
+
+fn run_hotfix(mut state: BTreeMap<String, String>, first_step: usize) -> Result<(), Error> {
+    let field = |state: &BTreeMap<String, String>, key: &str| -> Result<String, Error> {
+        state
+            .get(key)
+            .cloned()
+            .ok_or_else(|| Error::State(STATE_NAME.to_string()))
+    };
+    let name = field(&state, "branch")?;
+    let production = field(&state, "production")?;
+    let develop = field(&state, "develop")?;
+    let tag = field(&state, "tag")?;
+
+    for step in HotfixStep::ALL.into_iter().skip(first_step) {
+        // a merge step is only recorded once its target is checked out
+        match step {
+            HotfixStep::MergeProduction => checkout_updated(&production)?,
+            HotfixStep::MergeDevelop => checkout_updated(&develop)?,
+            _ => {}
+        }
+        state.insert("step".to_string(), step.as_str().to_string());
+        write_state(STATE_NAME, &state)?;
+        match step {
+            HotfixStep::MergeProduction => {
+                let message = field(&state, "message")?;
+                merge_or_pause(&["merge", "--no-ff", "-m", &message, &name], &production)?;
+            }
+            HotfixStep::Tag => {
+                create_annotated_tag(&tag, &format!("Release {}", tag))?;
+            }
+            HotfixStep::MergeDevelop => {
+                let message = format!("Merge {} ({}) back into {}", production, tag, develop);
+                merge_or_pause(&["merge", "--no-ff", "-m", &message, &production], &develop)?;
+            }
+            HotfixStep::Push => {
+                run_git(&["push", base_remote(), &production, &develop, &tag])?;
+            }
+            HotfixStep::Delete => {
+                delete_branch(&name)?;
+            }
+        }
+    }
+    clear_state(STATE_NAME)?;
+    println!(
+        "Finished {} into {} and {}, tagged {}",
+        name, production, develop, tag
+    );
+    Ok(())
+}
+
+/// Checks out `name` and fast-forwards it to its copy on the base remote,
+/// so the hotfix is not merged into a stale branch.
+fn checkout_updated(name: &str) -> Result<(), Error> {
+    checkout_branch(name)?;
+    let remote_branch = format!("{}/{}", base_remote(), name);
+    if ref_exists(&format!("refs/remotes/{}", remote_branch))? {
+        run_git(&["merge", "--ff-only", &remote_branch])?;
+    }
+    Ok(())
+}
+
+/// Runs a merge, pausing the flow when it stops on conflicts.
+fn merge_or_pause(args: &[&str], target: &str) -> Result<(), Error> {
+    if run_git(args).is_ok() {
+        return Ok(());
+    }
+    if is_merging()? {
+        return Err(Error::FlowPaused(
+            STATE_NAME.to_string(),
+            format!("Merging into {} stopped on conflicts", target),
+        ));
+    }
+    clear_state(STATE_NAME)?;
+    Err(Error::Merge(target.to_string()))
+}
+
+fn continue_hotfix() -> Result<(), Error> {
+    let state = read_state(STATE_NAME)?.ok_or(Error::NoFlowInProgress(STATE_NAME.to_string()))?;
+    let step = state
+        .get("step")
+        .and_then(|step| HotfixStep::parse(step))
+        .ok_or_else(|| Error::State(STATE_NAME.to_string()))?;
+    if is_merging()? {
+        // fails while conflicts are left, keeping the flow paused
+        run_git(&["commit", "--no-edit"]).map_err(|_| {
+            Error::FlowPaused(
+                STATE_NAME.to_string(),
+                "The merge still has unresolved conflicts".to_string(),
+            )
+        })?;
+    }
+    let done = is_step_done(step, &state)?;
+    run_hotfix(state, get_resume_step(step, done))
+}
+
+/// Checks the repository for the effect of `step`, so a step that failed
+/// before doing its work is run again instead of skipped.
+fn is_step_done(step: HotfixStep, state: &BTreeMap<String, String>) -> Result<bool, Error> {
+    let field = |key: &str| {
+        state
+            .get(key)
+            .cloned()
+            .ok_or_else(|| Error::State(STATE_NAME.to_string()))
+    };
+    match step {
+        HotfixStep::MergeProduction => is_merged(&field("branch")?, &field("production")?),
+        HotfixStep::Tag => ref_exists(&format!("refs/tags/{}", field("tag")?)),
+        HotfixStep::MergeDevelop => is_merged(&field("production")?, &field("develop")?),
+        HotfixStep::Push => Ok(false),
+        HotfixStep::Delete => Ok(!ref_exists(&format!("refs/heads/{}", field("branch")?))?),
+    }
+}
+
+/// The index in `HotfixStep::ALL` the flow resumes at: the step after
+/// `step` when it is done, or `step` itself.
+fn get_resume_step(step: HotfixStep, done: bool) -> usize {
+    let index = HotfixStep::ALL
+        .iter()
+        .position(|known| *known == step)
+        .unwrap_or_default();
+    if done {
+        index + 1
+    } else {
+        index
+    }
+}
+
+fn abort_hotfix() -> Result<(), Error> {
+    let state = read_state(STATE_NAME)?.ok_or(Error::NoFlowInProgress(STATE_NAME.to_string()))?;
+    if is_merging()? {
+        run_git(&["merge", "--abort"])?;
+    }
+    clear_state(STATE_NAME)?;
+    if state.get("step").map(String::as_str) != Some(HotfixStep::MergeProduction.as_str()) {
+        println!(
+            "{} was already merged into {} and tagged {}",
+            state.get("branch").cloned().unwrap_or_default(),
+            state.get("production").cloned().unwrap_or_default(),
+            state.get("tag").cloned().unwrap_or_default()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use super::*;
+
+#[test]
+fn test_make_merge_message() -> Result<(), Error> {
+    let branch = Branch::from("feature/12_add-some_thing")?;
+    assert_eq!(
+        make_merge_message(&branch, "feature/12_add-some_thing", "develop"),
+        "Merge #12 add some thing (feature/12_add-some_thing) into develop"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_make_merge_commands() {
+    let name = "feature/12_thing";
+    assert_eq!(
+        make_merge_commands(name, "develop", MergeStrategy::Merge, "msg"),
+        vec![
+            vec!["checkout", "develop"],
+            vec!["merge", "--no-ff", "-m", "msg", name],
+        ]
+    );
+    assert_eq!(
+        make_merge_commands(name, "develop", MergeStrategy::Squash, "msg"),
+        vec![
+            vec!["checkout", "develop"],
+            vec!["merge", "--squash", name],
+            vec!["commit", "-m", "msg"],
+        ]
+    );
+    assert_eq!(
+        make_merge_commands(name, "develop", MergeStrategy::Rebase, "msg"),
+        vec![
+            vec!["rebase", "develop", name],
+            vec!["checkout", "develop"],
+            vec!["merge", "--ff-only", name],
+        ]
+    );
+}
+
+#[test]
+fn test_hotfix_step_order() {
+    assert_eq!(
+        HotfixStep::ALL.map(|step| step.as_str()),
+        ["merge-production", "tag", "merge-develop", "push", "delete"]
+    );
+}
+
+#[test]
+fn test_hotfix_step_parse() {
+    for step in HotfixStep::ALL {
+        assert_eq!(HotfixStep::parse(step.as_str()), Some(step));
+    }
+    assert_eq!(HotfixStep::parse("merge"), None);
+}
+
+#[test]
+fn test_get_resume_step() {
+    assert_eq!(get_resume_step(HotfixStep::MergeProduction, false), 0);
+    assert_eq!(get_resume_step(HotfixStep::MergeProduction, true), 1);
+    assert_eq!(get_resume_step(HotfixStep::Tag, true), 2);
+    assert_eq!(get_resume_step(HotfixStep::MergeDevelop, false), 2);
+    assert_eq!(get_resume_step(HotfixStep::Delete, true), 5);
+}
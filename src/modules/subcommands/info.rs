@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests;
+
+use std::process::Command;
+
+use crate::{
+    modules::{
+        helpers::branch::{get_branch_base, resolve_branch},
+        structs::{branch::Branch, branch_metadata::BranchMetadata},
+        types::errors::Error,
+    },
+    InfoArgs,
+};
+
+pub fn exec(args: &InfoArgs) -> Result<(), Error> {
+    let name = match &args.branch_code {
+        Some(branch_code) => resolve_branch(branch_code, args.branch_type.as_deref(), args.pick)?,
+        None => Branch::get_raw_name()?,
+    };
+    let branch = Branch::from(&name)?;
+    if branch.is_special() {
+        return Err(Error::SpecialBranch(name));
+    }
+    let metadata = BranchMetadata::read(&name)?;
+    let base = get_branch_base(&name, branch.branch_type())?;
+
+    println!("Branch:  {}", name);
+    println!("Type:    {}", branch.branch_type());
+    if branch.is_release() {
+        println!("Version: {}", branch.code());
+    } else {
+        println!("Code:    {}", branch.code());
+        println!("Title:   {}", branch.title());
+    }
+    match &metadata {
+        Some(metadata) => {
+            println!("Source:  {}", metadata.source);
+            println!("Base:    {}", metadata.base);
+            println!("Created: {}", format_timestamp(metadata.created));
+        }
+        None => println!("Base:    {} (no metadata stored)", base),
+    }
+    // ahead counts from the recorded start, which moving the base does not
+    // change, behind counts what the base branch has gained since
+    let start = metadata
+        .as_ref()
+        .map(|metadata| metadata.base.as_str())
+        .filter(|start| !start.is_empty());
+    match count_commits(start.unwrap_or(&base), &name)? {
+        Some(ahead) => match start {
+            Some(start) => println!(
+                "Ahead:   {} (since {}, where the branch started)",
+                ahead,
+                &start[..start.len().min(7)]
+            ),
+            None => println!("Ahead:   {} (against {})", ahead, base),
+        },
+        None => println!("Ahead:   not available"),
+    }
+    match count_commits(&name, &base)? {
+        Some(behind) => println!(
+            "Behind:  {} (commits on {} not in the branch)",
+            behind, base
+        ),
+        None => println!("Behind:  not available, {} is missing", base),
+    }
+    Ok(())
+}
+
+/// Counts the commits on `to` that are not on `from`.
+fn count_commits(from: &str, to: &str) -> Result<Option<u64>, Error> {
+    let output = Command::new("git")
+        .arg("rev-list")
+        .arg("--count")
+        .arg(format!("{}..{}", from, to))
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Ok(None);
+    }
+    let count = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(count.trim().parse().ok())
+}
+
+/// Formats seconds since the Unix epoch as a UTC date and time.
+fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86400, timestamp % 86400);
+    // civil date from days since 1970-01-01, after Howard Hinnant
+    let days = days as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
@@ -0,0 +1,100 @@
+use crate::{
+    modules::{
+        helpers::{
+            branch::{filter_branches_by_code, get_branches},
+            log::{get_log, LogEntry},
+        },
+        structs::{branch::Branch, commit_message::CommitMessage, config::Config},
+        types::{commit_types::CommitType, errors::Error},
+    },
+    LogArgs,
+};
+
+type Group = (Option<String>, Vec<(LogEntry, CommitMessage)>);
+
+pub fn exec(args: &LogArgs) -> Result<(), Error> {
+    let commit_type = args
+        .commit_type
+        .as_deref()
+        .map(CommitType::parse)
+        .transpose()?;
+    let mut git_args = vec![];
+    if let Some(author) = &args.author {
+        git_args.push(format!("--author={}", author));
+    }
+    if let Some(since) = &args.since {
+        git_args.push(format!("--since={}", since));
+    }
+    let code = args
+        .code
+        .as_deref()
+        .map(|code| Config::get().branch.code_format.normalize(code));
+    let log = get_log(args.range.as_deref().unwrap_or("HEAD"), &git_args)?;
+
+    // groups keep the order in which their newest commit appears
+    let mut groups: Vec<Group> = vec![];
+    let mut skipped = 0;
+    for entry in log {
+        let Ok(message) = CommitMessage::parse(&entry.message) else {
+            skipped += 1;
+            continue;
+        };
+        if commit_type
+            .as_ref()
+            .is_some_and(|commit_type| *commit_type != message.commit_type)
+            || code
+                .as_ref()
+                .is_some_and(|code| Some(code) != message.code.as_ref())
+        {
+            continue;
+        }
+        match groups.iter_mut().find(|(code, _)| *code == message.code) {
+            Some((_, commits)) => commits.push((entry, message)),
+            None => groups.push((message.code.clone(), vec![(entry, message)])),
+        }
+    }
+
+    let branches = get_branches()?;
+    for (code, commits) in groups {
+        match &code {
+            Some(code) => match get_title(&branches, code) {
+                Some(title) => println!("#{} {}", code, title),
+                None => println!("#{}", code),
+            },
+            None => println!("No ticket"),
+        }
+        for (entry, message) in commits {
+            println!(
+                "  {} {}{}{}: {} ({}, {})",
+                &entry.sha[..entry.sha.len().min(7)],
+                message.commit_type.as_str(),
+                message
+                    .scope
+                    .map(|scope| format!("({})", scope))
+                    .unwrap_or_default(),
+                if message.breaking { "!" } else { "" },
+                if message.run_ci {
+                    Branch::RUN_CI
+                } else {
+                    &message.subject
+                },
+                entry.author,
+                entry.date
+            );
+        }
+    }
+    if skipped > 0 {
+        println!("{} commits not in the sam format were skipped", skipped);
+    }
+    Ok(())
+}
+
+/// Recovers a ticket's title from the branches that still carry its code.
+fn get_title(branches: &[String], code: &str) -> Option<String> {
+    let mut matches = filter_branches_by_code(branches, code);
+    matches.sort();
+    matches
+        .into_iter()
+        .find_map(|name| Branch::from(name).ok())
+        .map(|branch| branch.title().to_string())
+}
@@ -1,8 +1,21 @@
-use crate::modules::{helpers::branch::get_branches, types::errors::Error};
+use crate::modules::{
+    helpers::branch::get_branches,
+    structs::{branch::Branch, version::Version},
+    types::errors::Error,
+};
 
 pub fn exec() -> Result<(), Error> {
     let mut branches = get_branches()?;
-    branches.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+    // release branches are ordered by version so 1.10.0 comes after 1.9.0
+    branches.sort_by_key(
+        |name| match Branch::from(name).ok().filter(Branch::is_release) {
+            Some(branch) => (
+                format!("{}/", branch.branch_type()),
+                Version::parse(branch.code()).ok(),
+            ),
+            None => (name.to_lowercase(), None),
+        },
+    );
     for branch in branches {
         println!("{}", branch);
     }
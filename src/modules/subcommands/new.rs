@@ -1,9 +1,20 @@
+#[cfg(test)]
+mod tests;
+
 use std::process::Command;
 
 use crate::{
     modules::{
-        helpers::branch::{filter_branches_by_code, get_branches},
-        structs::branch::Branch,
+        helpers::{
+            branch::{filter_branches_by_code, get_base_branch, get_branches, resolve_branch},
+            log::get_log,
+            remote::resolve_ref,
+            repo::rev_parse,
+        },
+        structs::{
+            branch::Branch, branch_metadata::BranchMetadata, commit_message::CommitMessage,
+            config::Config,
+        },
         types::errors::Error,
     },
     NewArgs,
@@ -12,58 +23,138 @@ use crate::{
 pub fn exec(args: &NewArgs) -> Result<(), Error> {
     let branch_type = args.branch_type.as_str();
     let branch_code = args.branch_code.as_str();
-    let branch_name = args.branch_name.as_str();
+    let branch_name = args.branch_name.as_deref().unwrap_or_default();
     let source = &args.source;
     let literal_source = args.literal_source;
     let from_current = args.from_current;
     let raw_name = Branch::make_raw_name(branch_type, branch_code, branch_name)?;
-    let exit_code = if let Some(source) = source {
-        let source = if literal_source || Branch::SPECIAL_NAMES.contains(&source.as_str()) {
-            source.clone()
+    let code = Branch::from(&raw_name)?.code().to_string();
+    let raw_name = if args.continue_ticket {
+        make_follow_up_name(&raw_name, &code, branch_type, branch_name)?
+    } else {
+        if !args.force {
+            check_duplicate_code(&code)?;
+        }
+        raw_name
+    };
+    let (exit_code, source) = if let Some(source) = source {
+        let (source, start_point) = if literal_source {
+            (source.clone(), source.clone())
         } else {
-            let branches = get_branches()?;
-            let matches = filter_branches_by_code(&branches, source);
-            if matches.len() == 0 {
-                return Err(Error::BranchNotFoundOnCheckout(source.to_string()));
-            }
-            matches[0].clone()
+            let source = if Config::get().branch.is_special(source) {
+                source.clone()
+            } else {
+                resolve_branch(source, args.source_type.as_deref(), args.source_pick)?
+            };
+            // a branch only a remote has starts from its remote copy
+            let start_point = resolve_ref(&source)?;
+            (source, start_point)
         };
-        Command::new("git")
+        let exit_code = Command::new("git")
             .arg("checkout")
+            .arg("--no-track")
             .arg("-b")
             .arg(&raw_name)
-            .arg(source)
+            .arg(&start_point)
             .status()
             .map_err(|_| Error::Git)?
             .code()
-            .unwrap_or(-1)
+            .unwrap_or(-1);
+        (exit_code, source)
     } else if from_current {
-        Command::new("git")
+        let source = Branch::get_raw_name()?;
+        let exit_code = Command::new("git")
             .arg("checkout")
             .arg("-b")
             .arg(&raw_name)
             .status()
             .map_err(|_| Error::Git)?
             .code()
-            .unwrap_or(-1)
+            .unwrap_or(-1);
+        (exit_code, source)
     } else {
-        let source_branch = match branch_type {
-            "feature" => "develop",
-            "hotfix" => "master", // ! needs configuring
-            _ => return Err(Error::InvalidBranchType(branch_type.to_string())),
-        };
-        Command::new("git")
+        let source_branch = get_base_branch(branch_type)?;
+        let exit_code = Command::new("git")
             .arg("checkout")
+            .arg("--no-track")
             .arg("-b")
             .arg(&raw_name)
-            .arg(source_branch)
+            .arg(resolve_ref(&source_branch)?)
             .status()
             .map_err(|_| Error::Git)?
             .code()
-            .unwrap_or(-1)
+            .unwrap_or(-1);
+        (exit_code, source_branch)
     };
     if exit_code != 0 {
         return Err(Error::Git);
     }
-    Ok(())
+    let branch = Branch::from(&raw_name)?;
+    BranchMetadata::new(branch_type, branch.code(), &source, &rev_parse("HEAD")?).write(&raw_name)
+}
+
+/// Fails when a local or remote branch, or with `new.check-history` a
+/// commit, already carries `code`.
+fn check_duplicate_code(code: &str) -> Result<(), Error> {
+    let branches = get_branches()?;
+    let mut conflicts = filter_branches_by_code(&branches, code)
+        .into_iter()
+        .cloned()
+        .collect::<Vec<String>>();
+    conflicts.sort();
+    if conflicts.is_empty() && Config::get().new.check_history {
+        let grep = format!("--grep={}", code);
+        let log = get_log("--all", &["--fixed-strings".to_string(), grep])?;
+        conflicts = log
+            .iter()
+            .filter(|entry| {
+                CommitMessage::parse(&entry.message)
+                    .is_ok_and(|message| message.code.as_deref() == Some(code))
+            })
+            .map(|entry| format!("commit {}", &entry.sha[..entry.sha.len().min(7)]))
+            .collect();
+    }
+    if conflicts.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::DuplicateCode(code.to_string(), conflicts))
+    }
+}
+
+/// Numbers the title of a follow-up branch after the branches that already
+/// carry `code`, skipping names that are taken.
+fn make_follow_up_name(
+    raw_name: &str,
+    code: &str,
+    branch_type: &str,
+    branch_name: &str,
+) -> Result<String, Error> {
+    // release branches are named by version alone and cannot be numbered
+    if Config::get().branch.is_release_type(branch_type) {
+        check_duplicate_code(code)?;
+        return Ok(raw_name.to_string());
+    }
+    let branches = get_branches()?;
+    let existing = filter_branches_by_code(&branches, code);
+    if existing.is_empty() {
+        return Ok(raw_name.to_string());
+    }
+    (existing.len() + 1..)
+        .map(|number| {
+            Branch::make_raw_name(
+                branch_type,
+                code,
+                &make_follow_up_title(branch_name, number),
+            )
+        })
+        .find(|name| !name.as_ref().is_ok_and(|name| branches.contains(name)))
+        .unwrap_or(Err(Error::NameFormat))
+}
+
+fn make_follow_up_title(branch_name: &str, number: usize) -> String {
+    if branch_name.is_empty() {
+        number.to_string()
+    } else {
+        format!("{}-{}", branch_name, number)
+    }
 }
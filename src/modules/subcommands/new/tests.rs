@@ -0,0 +1,16 @@
+use super::*;
+
+#[test]
+fn test_make_follow_up_title() {
+    assert_eq!(make_follow_up_title("fix-login", 2), "fix-login-2");
+    assert_eq!(make_follow_up_title("", 3), "3");
+}
+
+#[test]
+fn test_follow_up_names_are_valid() -> Result<(), Error> {
+    assert_eq!(
+        Branch::make_raw_name("feature", "12", &make_follow_up_title("fix-login", 2))?,
+        "feature/12_fix-login-2"
+    );
+    Ok(())
+}
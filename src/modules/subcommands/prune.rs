@@ -0,0 +1,204 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+
+use crate::{
+    modules::{
+        helpers::{
+            branch::{is_merged, is_merged_into_base, list_refs},
+            remote::{base_remote, push_remote},
+            repo::{rev_parse, run_git},
+        },
+        structs::{branch::Branch, branch_metadata::BranchMetadata, config::Config},
+        types::errors::Error,
+    },
+    PruneArgs,
+};
+
+const LOCAL_NAMESPACE: &str = "refs/heads/";
+
+#[derive(Serialize)]
+struct Candidate {
+    name: String,
+    location: &'static str,
+    reason: String,
+    /// Seconds since the Unix epoch.
+    last_commit: u64,
+    /// Stale branches are listed but only deleted with `--force`.
+    merged: bool,
+}
+
+pub fn exec(args: &PruneArgs) -> Result<(), Error> {
+    // neither flag means both
+    let (local, remote) = (args.local || !args.remote, args.remote || !args.local);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let stale_before = args.days.map(|days| now.saturating_sub(days * 86400));
+    let current = Branch::get_raw_name()?;
+
+    // unlike `delete`, only the push remote is pruned: the base remote of a
+    // fork belongs to someone else
+    let remote_namespace = format!("refs/remotes/{}/", push_remote());
+    let mut candidates = vec![];
+    for (namespace, location, enabled) in [
+        (LOCAL_NAMESPACE, "local", local),
+        (remote_namespace.as_str(), "remote", remote),
+    ] {
+        if !enabled {
+            continue;
+        }
+        let mut names = list_refs(namespace)?;
+        names.sort();
+        for name in names {
+            if namespace == LOCAL_NAMESPACE && name == current {
+                continue;
+            }
+            let reference = format!("{}{}", namespace, name);
+            if let Some((reason, last_commit, merged)) =
+                get_reason(&reference, &name, stale_before)?
+            {
+                candidates.push(Candidate {
+                    name,
+                    location,
+                    reason,
+                    last_commit,
+                    merged,
+                });
+            }
+        }
+    }
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&candidates)
+            .map_err(|error| Error::Json(error.to_string()))?;
+        println!("{}", json);
+    } else if candidates.is_empty() {
+        println!("Nothing to prune");
+    } else {
+        for candidate in &candidates {
+            println!(
+                "{}\t{}\t{}",
+                candidate.location, candidate.name, candidate.reason
+            );
+        }
+    }
+    // JSON output is only a list unless the deletion needs no question
+    if args.dry_run || (args.json && !args.yes) {
+        return Ok(());
+    }
+
+    let (remote_candidates, local_candidates): (Vec<&Candidate>, Vec<&Candidate>) = candidates
+        .iter()
+        .filter(|candidate| candidate.merged || args.force)
+        .partition(|candidate| candidate.location == "remote");
+    if !args.force && !args.json && candidates.iter().any(|candidate| !candidate.merged) {
+        println!("Keeping the stale branches that are not merged, pass --force to delete them");
+    }
+    if local_candidates.is_empty() && remote_candidates.is_empty() {
+        return Ok(());
+    }
+    if !args.yes && !confirm("Delete these branches?")? {
+        println!("Nothing deleted, pass --yes to delete without asking");
+        return Ok(());
+    }
+    if !local_candidates.is_empty() {
+        let mut git_args = vec!["branch", "-D"];
+        git_args.extend(
+            local_candidates
+                .iter()
+                .map(|candidate| candidate.name.as_str()),
+        );
+        run_git(&git_args)?;
+    }
+    if !remote_candidates.is_empty() {
+        let mut git_args = vec!["push", push_remote(), "--delete"];
+        git_args.extend(
+            remote_candidates
+                .iter()
+                .map(|candidate| candidate.name.as_str()),
+        );
+        run_git(&git_args)?;
+    }
+    Ok(())
+}
+
+/// Asks a yes or no question on the terminal. Without a terminal to ask on
+/// the answer is no.
+fn confirm(question: &str) -> Result<bool, Error> {
+    if !io::stdin().is_terminal() {
+        return Ok(false);
+    }
+    print!("{} [y/N] ", question);
+    io::stdout().flush().map_err(|_| Error::Git)?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).map_err(|_| Error::Git)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Tells why a ticket branch can be pruned, with the time of its last
+/// commit and whether it is merged, or `None` when it must be kept.
+/// Special names and other branches that are not ticket branches are
+/// always kept.
+fn get_reason(
+    reference: &str,
+    name: &str,
+    stale_before: Option<u64>,
+) -> Result<Option<(String, u64, bool)>, Error> {
+    let config = &Config::get().branch;
+    let Ok(branch) = Branch::from(name) else {
+        return Ok(None);
+    };
+    if branch.is_special() || config.is_special(name) || !config.is_valid_type(branch.branch_type())
+    {
+        return Ok(None);
+    }
+    // a branch without commits of its own is new, not merged
+    if let Some(metadata) = BranchMetadata::read(name)? {
+        if !metadata.base.is_empty() && rev_parse(reference)? == metadata.base {
+            return Ok(None);
+        }
+    }
+    let last_commit = get_commit_time(reference)?;
+    if is_merged_into_base(reference, name, branch.branch_type())? {
+        return Ok(Some((
+            "merged into its base".to_string(),
+            last_commit,
+            true,
+        )));
+    }
+    let production = &config.production;
+    if is_merged(reference, production)?
+        || is_merged(reference, &format!("{}/{}", base_remote(), production))?
+    {
+        return Ok(Some((
+            format!("merged into {}", production),
+            last_commit,
+            true,
+        )));
+    }
+    if stale_before.is_some_and(|stale_before| last_commit < stale_before) {
+        return Ok(Some(("stale, not merged".to_string(), last_commit, false)));
+    }
+    Ok(None)
+}
+
+fn get_commit_time(reference: &str) -> Result<u64, Error> {
+    let output = Command::new("git")
+        .arg("log")
+        .arg("-1")
+        .arg("--format=%ct")
+        .arg(reference)
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let time = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    Ok(time.trim().parse().unwrap_or_default())
+}
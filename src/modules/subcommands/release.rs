@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests;
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    modules::{
+        helpers::{
+            log::{get_log, LogEntry},
+            repo::{commit_paths, get_root},
+            tag::{create_annotated_tag, get_last_release_tag, get_version_tags, make_tag_name},
+        },
+        structs::{
+            commit_message::CommitMessage,
+            config::Config,
+            version::{Bump, Version},
+            version_file::VersionFile,
+        },
+        types::{commit_types::CommitType, errors::Error},
+    },
+    ReleaseArgs,
+};
+
+pub fn exec(args: &ReleaseArgs) -> Result<(), Error> {
+    let last_release = get_last_release_tag("HEAD")?;
+    let range = match &last_release {
+        Some((tag, _)) => format!("{}..HEAD", tag),
+        None => "HEAD".to_string(),
+    };
+    let commits = get_log(&range, &[])?
+        .into_iter()
+        .filter_map(|entry| {
+            let message = CommitMessage::parse(&entry.message).ok()?;
+            Some((entry, message))
+        })
+        .collect::<Vec<(LogEntry, CommitMessage)>>();
+    let base = last_release
+        .map(|(_, version)| version)
+        .unwrap_or(Version::parse("0.0.0")?);
+    let (bump, mut version) = get_next_version(&base, commits.iter().map(|(_, message)| message))
+        .ok_or(Error::NothingToRelease)?;
+    if let Some(pre) = &args.pre {
+        version = make_pre_release(&version, pre)?;
+    }
+    let tag = make_tag_name(&version);
+    let version_files = get_version_files()?;
+
+    if args.dry_run {
+        println!(
+            "{} ({} bump from {})",
+            tag,
+            bump.as_str(),
+            make_tag_name(&base)
+        );
+        for (entry, message) in &commits {
+            println!(
+                "  {} {:<5} {}",
+                &entry.sha[..entry.sha.len().min(7)],
+                get_bump(message).map_or("", |bump| bump.as_str()),
+                entry.message.lines().next().unwrap_or_default()
+            );
+        }
+        let root = get_root()?;
+        for version_file in &version_files {
+            println!(
+                "{}: {} -> {}",
+                version_file
+                    .path()
+                    .strip_prefix(&root)
+                    .unwrap_or(version_file.path())
+                    .display(),
+                version_file.read_version()?.unwrap_or_default(),
+                version
+            );
+        }
+        return Ok(());
+    }
+    commit_version_files(&version_files, &version, &tag)?;
+    create_annotated_tag(&tag, &format!("Release {}", tag))?;
+    println!("Created tag {}", tag);
+    Ok(())
+}
+
+fn get_version_files() -> Result<Vec<VersionFile>, Error> {
+    let paths = &Config::get().release.version_files;
+    if paths.is_empty() {
+        return Ok(vec![]);
+    }
+    let root = get_root()?;
+    paths
+        .iter()
+        .map(|path| VersionFile::new(&root.join(path)))
+        .collect()
+}
+
+/// Writes the new version to the version files and records it in a
+/// `chore: release <tag>` commit holding only those files.
+fn commit_version_files(
+    version_files: &[VersionFile],
+    version: &Version,
+    tag: &str,
+) -> Result<(), Error> {
+    // the message does not depend on the checked out branch, which may be a
+    // detached HEAD in CI
+    let message = make_release_message(tag);
+    let mut changed = vec![];
+    for version_file in version_files {
+        changed.append(&mut version_file.write_version(&version.to_string())?);
+    }
+    if changed.is_empty() {
+        return Ok(());
+    }
+    commit_paths(
+        &changed.iter().map(PathBuf::as_path).collect::<Vec<&Path>>(),
+        &message,
+    )
+}
+
+fn make_release_message(tag: &str) -> String {
+    format!("{}: release {}", CommitType::Chore.as_str(), tag)
+}
+
+/// Bumps `base` by the largest bump the commits call for, or returns `None`
+/// when none of them calls for a release.
+fn get_next_version<'a>(
+    base: &Version,
+    messages: impl Iterator<Item = &'a CommitMessage>,
+) -> Option<(Bump, Version)> {
+    let bump = messages.filter_map(get_bump).max()?;
+    Some((bump, base.bump(bump)))
+}
+
+/// Breaking changes bump the major version, features the minor and fixes
+/// the patch. Other types do not call for a release.
+fn get_bump(message: &CommitMessage) -> Option<Bump> {
+    if message.breaking {
+        return Some(Bump::Major);
+    }
+    match message.commit_type {
+        CommitType::Feat => Some(Bump::Minor),
+        CommitType::Fix | CommitType::Perf => Some(Bump::Patch),
+        _ => None,
+    }
+}
+
+/// Numbers pre-releases of the same version in sequence: `1.4.0-rc.1`,
+/// `1.4.0-rc.2` and so on.
+fn make_pre_release(version: &Version, pre: &str) -> Result<Version, Error> {
+    let last_number = get_version_tags()?
+        .iter()
+        .filter(|(_, tag_version)| tag_version.same_core(version))
+        .filter_map(|(_, tag_version)| tag_version.pre_number(pre))
+        .max()
+        .unwrap_or(0);
+    let version = version.with_pre(&format!("{}.{}", pre, last_number + 1));
+    // validates the identifier given by the user
+    Version::parse(&version.to_string())
+}
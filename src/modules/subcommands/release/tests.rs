@@ -0,0 +1,56 @@
+use super::*;
+
+fn parse_all(raw: &[&str]) -> Vec<CommitMessage> {
+    raw.iter()
+        .map(|raw| CommitMessage::parse(raw).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_get_bump() {
+    let bumps = parse_all(&[
+        "feat(#12): add a thing",
+        "fix(#12): fix a thing",
+        "perf: faster",
+        "docs: write it down",
+        "refactor!: drop the old api",
+        "chore: tidy\n\nBREAKING CHANGE: drops v1",
+    ])
+    .iter()
+    .map(get_bump)
+    .collect::<Vec<Option<Bump>>>();
+    assert_eq!(
+        bumps,
+        vec![
+            Some(Bump::Minor),
+            Some(Bump::Patch),
+            Some(Bump::Patch),
+            None,
+            Some(Bump::Major),
+            Some(Bump::Major),
+        ]
+    );
+}
+
+#[test]
+fn test_get_next_version() -> Result<(), Error> {
+    let base = Version::parse("1.4.2")?;
+    let messages = parse_all(&["fix: a", "feat: b", "docs: c"]);
+    assert_eq!(
+        get_next_version(&base, messages.iter()),
+        Some((Bump::Minor, Version::parse("1.5.0")?))
+    );
+    let messages = parse_all(&["fix: a", "feat!: b"]);
+    assert_eq!(
+        get_next_version(&base, messages.iter()),
+        Some((Bump::Major, Version::parse("2.0.0")?))
+    );
+    let messages = parse_all(&["docs: a", "chore: b"]);
+    assert_eq!(get_next_version(&base, messages.iter()), None);
+    Ok(())
+}
+
+#[test]
+fn test_make_release_message() {
+    assert_eq!(make_release_message("v1.4.0"), "chore: release v1.4.0");
+}
@@ -0,0 +1,46 @@
+use crate::{
+    modules::{
+        helpers::{
+            remote::push_remote,
+            repo::{ref_exists, run_git},
+        },
+        structs::{branch::Branch, branch_metadata::BranchMetadata},
+        types::errors::Error,
+    },
+    RenameArgs,
+};
+
+pub fn exec(args: &RenameArgs) -> Result<(), Error> {
+    let name = Branch::get_raw_name()?;
+    let branch = Branch::from(&name)?;
+    if branch.is_special() {
+        return Err(Error::SpecialBranch(name));
+    }
+    let branch_type = args.branch_type.as_deref().unwrap_or(branch.branch_type());
+    let branch_code = args.code.as_deref().unwrap_or(branch.code());
+    let branch_title = args.title.as_deref().unwrap_or(branch.title());
+    let new_name = Branch::make_raw_name(branch_type, branch_code, branch_title)?;
+    if new_name == name {
+        println!("{} already has this name", name);
+        return Ok(());
+    }
+    if ref_exists(&format!("refs/heads/{}", new_name))? {
+        return Err(Error::BranchExists(new_name));
+    }
+
+    // moves the branch.<name>.* config, stored metadata included
+    run_git(&["branch", "-m", &name, &new_name])?;
+    if let Some(mut metadata) = BranchMetadata::read(&new_name)? {
+        let new_branch = Branch::from(&new_name)?;
+        metadata.branch_type = new_branch.branch_type().to_string();
+        metadata.code = new_branch.code().to_string();
+        metadata.write(&new_name)?;
+    }
+    let remote = push_remote();
+    if !args.local_only && ref_exists(&format!("refs/remotes/{}/{}", remote, name))? {
+        run_git(&["push", "-u", remote, &new_name])?;
+        run_git(&["push", remote, "--delete", &name])?;
+    }
+    println!("Renamed {} to {}", name, new_name);
+    Ok(())
+}
@@ -1,12 +1,19 @@
 use crate::modules::{
     helpers::push::git_push,
-    structs::branch::Branch,
+    structs::{branch::Branch, commit_options::CommitOptions},
     types::{commit_types::CommitType, errors::Error},
 };
 
 pub fn exec() -> Result<(), Error> {
     let branch = Branch::new()?;
-    branch.commit(CommitType::Chore, Branch::RUN_CI, true, true, false)?;
+    branch.commit(
+        CommitType::Chore,
+        Branch::RUN_CI,
+        &CommitOptions::default(),
+        true,
+        true,
+        false,
+    )?;
     git_push()?;
     Ok(())
 }
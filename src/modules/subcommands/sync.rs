@@ -0,0 +1,179 @@
+use std::{
+    collections::BTreeMap,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    modules::{
+        helpers::{
+            branch::get_branch_base,
+            remote::base_remote,
+            repo::{is_clean, is_merging, is_rebasing, ref_exists, rev_parse, run_git},
+            state::{clear_state, read_state, write_state},
+        },
+        structs::{branch::Branch, config::Config},
+        types::{errors::Error, sync_strategy::SyncStrategy},
+    },
+    SyncArgs,
+};
+
+const STATE_NAME: &str = "sync";
+const STASH_MESSAGE: &str = "git sam sync";
+
+pub fn exec(args: &SyncArgs) -> Result<(), Error> {
+    if args.continue_flow {
+        return continue_sync();
+    }
+    if args.abort {
+        return abort_sync();
+    }
+    if read_state(STATE_NAME)?.is_some() {
+        return Err(Error::FlowInProgress(STATE_NAME.to_string()));
+    }
+    let name = Branch::get_raw_name()?;
+    let branch = Branch::from(&name)?;
+    if branch.is_special() {
+        return Err(Error::SpecialBranch(name));
+    }
+    let base = get_branch_base(&name, branch.branch_type())?;
+    let strategy = args.strategy.unwrap_or(Config::get().sync.strategy);
+
+    run_git(&["fetch", base_remote()])?;
+    let onto = get_remote_base(&base)?;
+    let stash = if is_clean()? {
+        String::new()
+    } else {
+        stash_changes(&name)?
+    };
+    let state = BTreeMap::from([
+        ("branch".to_string(), name.clone()),
+        ("onto".to_string(), onto.clone()),
+        ("strategy".to_string(), strategy.as_str().to_string()),
+        ("stash".to_string(), stash.clone()),
+    ]);
+    write_state(STATE_NAME, &state)?;
+
+    let result = match strategy {
+        SyncStrategy::Rebase => run_git(&["rebase", &onto]),
+        SyncStrategy::Merge => run_git(&[
+            "merge",
+            "-m",
+            &format!("Merge {} into {}", onto, name),
+            &onto,
+        ]),
+    };
+    if result.is_err() {
+        if is_rebasing()? || is_merging()? {
+            return Err(Error::FlowPaused(
+                STATE_NAME.to_string(),
+                format!("Syncing {} onto {} stopped on conflicts", name, onto),
+            ));
+        }
+        clear_state(STATE_NAME)?;
+        restore_stash(&stash)?;
+        return Err(Error::Merge(onto));
+    }
+    finish_sync(&state)
+}
+
+/// Prefers the copy of `base` on the base remote so the branch lands on
+/// what was just fetched.
+fn get_remote_base(base: &str) -> Result<String, Error> {
+    let remote_base = format!("{}/{}", base_remote(), base);
+    if ref_exists(&format!("refs/remotes/{}", remote_base))? {
+        Ok(remote_base)
+    } else {
+        Ok(base.to_string())
+    }
+}
+
+fn finish_sync(state: &BTreeMap<String, String>) -> Result<(), Error> {
+    clear_state(STATE_NAME)?;
+    restore_stash(get_stash(state))?;
+    println!(
+        "Synced {} onto {}",
+        state.get("branch").cloned().unwrap_or_default(),
+        state.get("onto").cloned().unwrap_or_default()
+    );
+    Ok(())
+}
+
+/// Stashes the changes under a message naming this sync and returns the
+/// commit of the new stash entry.
+fn stash_changes(name: &str) -> Result<String, Error> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let message = format!("{} {} {}", STASH_MESSAGE, name, now);
+    run_git(&["stash", "push", "--include-untracked", "-m", &message])?;
+    rev_parse("stash@{0}")
+}
+
+/// The commit of the stash entry the sync created, empty when nothing was
+/// stashed.
+fn get_stash(state: &BTreeMap<String, String>) -> &str {
+    state.get("stash").map_or("", String::as_str)
+}
+
+/// Pops the stash entry whose commit is `stash`, wherever other stashes
+/// pushed since then have moved it.
+fn restore_stash(stash: &str) -> Result<(), Error> {
+    if stash.is_empty() {
+        return Ok(());
+    }
+    let output = Command::new("git")
+        .arg("stash")
+        .arg("list")
+        .arg("--format=%H")
+        .output()
+        .map_err(|_| Error::Git)?;
+    if output.status.code().unwrap_or(-1) != 0 {
+        return Err(Error::Git);
+    }
+    let stashes = String::from_utf8(output.stdout).map_err(|_| Error::StringFormat)?;
+    let index = stashes
+        .lines()
+        .position(|sha| sha.trim() == stash)
+        .ok_or_else(|| Error::StashNotFound(stash.to_string()))?;
+    run_git(&["stash", "pop", &format!("stash@{{{}}}", index)]).map_err(|_| Error::StashPop)
+}
+
+fn continue_sync() -> Result<(), Error> {
+    let state = read_state(STATE_NAME)?.ok_or(Error::NoFlowInProgress(STATE_NAME.to_string()))?;
+    let paused = || {
+        Error::FlowPaused(
+            STATE_NAME.to_string(),
+            "The sync still has unresolved conflicts".to_string(),
+        )
+    };
+    if is_rebasing()? {
+        // keep the messages of the rebased commits instead of opening an editor
+        let exit_code = Command::new("git")
+            .arg("rebase")
+            .arg("--continue")
+            .env("GIT_EDITOR", "true")
+            .status()
+            .map_err(|_| Error::Git)?
+            .code()
+            .unwrap_or(-1);
+        if exit_code != 0 {
+            return Err(paused());
+        }
+    } else if is_merging()? {
+        run_git(&["commit", "--no-edit"]).map_err(|_| paused())?;
+    }
+    finish_sync(&state)
+}
+
+fn abort_sync() -> Result<(), Error> {
+    let state = read_state(STATE_NAME)?.ok_or(Error::NoFlowInProgress(STATE_NAME.to_string()))?;
+    if is_rebasing()? {
+        run_git(&["rebase", "--abort"])?;
+    } else if is_merging()? {
+        run_git(&["merge", "--abort"])?;
+    }
+    clear_state(STATE_NAME)?;
+    restore_stash(get_stash(&state))
+}
@@ -1,2 +1,5 @@
 pub mod errors;
-pub mod commit_types;
\ No newline at end of file
+pub mod code_format;
+pub mod commit_types;
+pub mod merge_strategy;
+pub mod sync_strategy;
\ No newline at end of file
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests;
+
+use serde::{Deserialize, Serialize};
+
+/// How ticket codes are written in branch names and commit scopes.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum CodeFormat {
+    /// `4312`
+    #[default]
+    Digits,
+    /// `PROJ-123`, an upper case project key and an issue number
+    Jira,
+    /// `#123`, stored without the `#` in branch names
+    Github,
+}
+
+impl CodeFormat {
+    /// Turns a code typed by the user into the form used in branch names.
+    pub fn normalize(&self, code: &str) -> String {
+        match self {
+            CodeFormat::Digits => code.to_string(),
+            CodeFormat::Jira => code.to_ascii_uppercase(),
+            CodeFormat::Github => code.strip_prefix('#').unwrap_or(code).to_string(),
+        }
+    }
+
+    pub fn is_valid(&self, code: &str) -> bool {
+        !code.is_empty() && self.code_len(code) == Some(code.len())
+    }
+
+    /// Returns the length of the code `name` starts with. The code ends at
+    /// the first character that can not continue it, so a following `_` or
+    /// `-` title separator is never part of it.
+    pub fn code_len(&self, name: &str) -> Option<usize> {
+        let digits_len = |rest: &str| {
+            let len = rest.bytes().take_while(|c| c.is_ascii_digit()).count();
+            (len > 0).then_some(len)
+        };
+        match self {
+            CodeFormat::Digits | CodeFormat::Github => digits_len(name),
+            CodeFormat::Jira => {
+                if !name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                    return None;
+                }
+                let key_len = name
+                    .bytes()
+                    .take_while(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+                    .count();
+                let number = name[key_len..].strip_prefix('-')?;
+                Some(key_len + 1 + digits_len(number)?)
+            }
+        }
+    }
+}
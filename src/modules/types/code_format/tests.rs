@@ -0,0 +1,38 @@
+use super::*;
+
+#[test]
+fn test_code_len_digits() {
+    assert_eq!(CodeFormat::Digits.code_len("4312_title"), Some(4));
+    assert_eq!(CodeFormat::Digits.code_len("4312-title"), Some(4));
+    assert_eq!(CodeFormat::Digits.code_len("title"), None);
+}
+
+#[test]
+fn test_code_len_jira() {
+    assert_eq!(CodeFormat::Jira.code_len("PROJ-123_title"), Some(8));
+    assert_eq!(CodeFormat::Jira.code_len("PROJ-123-title"), Some(8));
+    assert_eq!(CodeFormat::Jira.code_len("AB2-7"), Some(5));
+    assert_eq!(CodeFormat::Jira.code_len("PROJ_123"), None);
+    assert_eq!(CodeFormat::Jira.code_len("PROJ-title"), None);
+    assert_eq!(CodeFormat::Jira.code_len("proj-123"), None);
+    assert_eq!(CodeFormat::Jira.code_len("123"), None);
+}
+
+#[test]
+fn test_is_valid() {
+    assert!(CodeFormat::Digits.is_valid("12"));
+    assert!(!CodeFormat::Digits.is_valid("12a"));
+    assert!(!CodeFormat::Digits.is_valid(""));
+    assert!(CodeFormat::Jira.is_valid("PROJ-12"));
+    assert!(!CodeFormat::Jira.is_valid("PROJ-12-"));
+    assert!(CodeFormat::Github.is_valid("12"));
+    assert!(!CodeFormat::Github.is_valid("#12"));
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(CodeFormat::Github.normalize("#12"), "12");
+    assert_eq!(CodeFormat::Github.normalize("12"), "12");
+    assert_eq!(CodeFormat::Jira.normalize("proj-12"), "PROJ-12");
+    assert_eq!(CodeFormat::Digits.normalize("#12"), "#12");
+}
@@ -1,6 +1,115 @@
+#[cfg(test)]
+mod tests;
+
+use crate::modules::{structs::config::Config, types::errors::Error};
+
+#[derive(PartialEq, Debug, Clone)]
 pub enum CommitType {
     Feat,
     Chore,
     Style,
     Fix,
+    Docs,
+    Refactor,
+    Test,
+    Perf,
+    Build,
+    Ci,
+    Revert,
+    Custom(String),
+}
+
+impl CommitType {
+    pub const STANDARD: [CommitType; 11] = [
+        CommitType::Feat,
+        CommitType::Fix,
+        CommitType::Docs,
+        CommitType::Style,
+        CommitType::Refactor,
+        CommitType::Perf,
+        CommitType::Test,
+        CommitType::Build,
+        CommitType::Ci,
+        CommitType::Chore,
+        CommitType::Revert,
+    ];
+
+    /// Resolves a type name or a custom type alias from the config.
+    pub fn parse(name: &str) -> Result<Self, Error> {
+        if let Some(commit_type) = Self::STANDARD
+            .iter()
+            .find(|commit_type| commit_type.as_str() == name)
+        {
+            return Ok(commit_type.clone());
+        }
+        Config::get()
+            .commit
+            .types
+            .iter()
+            .find(|(custom_name, custom_type)| {
+                *custom_name == name || custom_type.aliases.iter().any(|alias| alias == name)
+            })
+            .map(|(custom_name, _)| CommitType::Custom(custom_name.clone()))
+            .ok_or_else(|| Error::CommitType(name.to_string(), Self::valid_types()))
+    }
+
+    /// Every type name accepted by `parse` with its description, without
+    /// aliases.
+    pub fn valid_types() -> Vec<(String, String)> {
+        Self::STANDARD
+            .iter()
+            .cloned()
+            .chain(
+                Config::get()
+                    .commit
+                    .types
+                    .keys()
+                    .map(|name| CommitType::Custom(name.clone())),
+            )
+            .map(|commit_type| (commit_type.as_str().to_string(), commit_type.description()))
+            .collect()
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            CommitType::Feat => "feat",
+            CommitType::Chore => "chore",
+            CommitType::Style => "style",
+            CommitType::Fix => "fix",
+            CommitType::Docs => "docs",
+            CommitType::Refactor => "refactor",
+            CommitType::Test => "test",
+            CommitType::Perf => "perf",
+            CommitType::Build => "build",
+            CommitType::Ci => "ci",
+            CommitType::Revert => "revert",
+            CommitType::Custom(name) => name,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            CommitType::Feat => "Features",
+            CommitType::Chore => "Chores",
+            CommitType::Style => "Styles",
+            CommitType::Fix => "Bug Fixes",
+            CommitType::Docs => "Documentation",
+            CommitType::Refactor => "Code Refactoring",
+            CommitType::Test => "Tests",
+            CommitType::Perf => "Performance Improvements",
+            CommitType::Build => "Build System",
+            CommitType::Ci => "Continuous Integration",
+            CommitType::Revert => "Reverts",
+            CommitType::Custom(name) => {
+                return Config::get()
+                    .commit
+                    .types
+                    .get(name)
+                    .map(|custom_type| custom_type.description.clone())
+                    .filter(|description| !description.is_empty())
+                    .unwrap_or_else(|| name.clone())
+            }
+        }
+        .to_string()
+    }
 }
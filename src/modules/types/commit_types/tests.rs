@@ -0,0 +1,24 @@
+use super::*;
+
+#[test]
+fn test_parse_standard() -> Result<(), Error> {
+    assert_eq!(CommitType::parse("refactor")?, CommitType::Refactor);
+    assert_eq!(CommitType::parse("ci")?, CommitType::Ci);
+    Ok(())
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(matches!(
+        CommitType::parse("feature"),
+        Err(Error::CommitType(name, valid_types)) if name == "feature" && valid_types.len() == 11
+    ));
+}
+
+#[test]
+fn test_as_str_round_trip() -> Result<(), Error> {
+    for commit_type in CommitType::STANDARD.iter() {
+        assert_eq!(&CommitType::parse(commit_type.as_str())?, commit_type);
+    }
+    Ok(())
+}
@@ -6,11 +6,44 @@ pub enum Error {
     StringFormat,
     Add,
     Git,
-    CommitType,
+    CommitType(String, Vec<(String, String)>),
     Commit,
     BranchCode,
     BranchNotFoundOnCheckout(String),
     InvalidBranchType(String),
+    Config(String),
+    GitConfigValue(String, String, String),
+    BaseBranchNotFound(String),
+    ConfigKey(String),
+    ConfigKeyNotSet(String),
+    CommitScope(String),
+    CommitFooter(String),
+    CommitHeader(String),
+    CommitParse(CommitParseError),
+    EmptyCommitMessage,
+    Editor,
+    Changelog(String),
+    Version(String),
+    NothingToRelease,
+    VersionFile(String),
+    DirtyTree,
+    SpecialBranch(String),
+    Merge(String),
+    State(String),
+    FlowPaused(String, String),
+    FlowInProgress(String),
+    NoFlowInProgress(String),
+    StashPop,
+    StashNotFound(String),
+    BranchExists(String),
+    CurrentBranch(String),
+    Unmerged(Vec<String>),
+    Json(String),
+    DuplicateCode(String, Vec<String>),
+    AmbiguousCode(String, Vec<String>),
+    InvalidPick(usize, usize),
+    AmbiguousTitle(String, Vec<String>),
+    NoTitleMatch(String),
 }
 
 impl fmt::Display for Error {
@@ -29,8 +62,12 @@ impl fmt::Display for Error {
             Error::Add => {
                 write!(f, "Some error occurred while running git add.")
             }
-            Error::CommitType => {
-                write!(f, "Invalid commit type.")
+            Error::CommitType(commit_type, valid_types) => {
+                write!(f, "Invalid commit type {}. Valid types are:", commit_type)?;
+                for (name, description) in valid_types {
+                    write!(f, "\n  {:<10} {}", name, description)?;
+                }
+                Ok(())
             }
             Error::Commit => {
                 write!(f, "Some error happened while committing.")
@@ -44,6 +81,230 @@ impl fmt::Display for Error {
             Error::InvalidBranchType(branch_type) => {
                 write!(f, "Branch type {} is invalid", branch_type)
             }
+            Error::Config(message) => {
+                write!(f, "Invalid .sam.toml config: {}", message)
+            }
+            Error::GitConfigValue(key, scope, message) => {
+                write!(
+                    f,
+                    "Invalid value for git config {} ({} scope): {}",
+                    key, scope, message
+                )
+            }
+            Error::BaseBranchNotFound(branch_type) => {
+                write!(
+                    f,
+                    "No base branch is configured for branch type {} and the remote has no default branch",
+                    branch_type
+                )
+            }
+            Error::ConfigKey(key) => {
+                write!(f, "{} is not a sam config key", key)
+            }
+            Error::ConfigKeyNotSet(key) => {
+                write!(f, "Config key {} is not set", key)
+            }
+            Error::CommitScope(scope) => {
+                write!(f, "Invalid commit scope {}", scope)
+            }
+            Error::CommitFooter(footer) => {
+                write!(
+                    f,
+                    "Invalid commit footer {}. Footers are written as key=value",
+                    footer
+                )
+            }
+            Error::CommitHeader(header) => {
+                write!(
+                    f,
+                    "Commit header \"{}\" is broken. It should look like type(scope): message",
+                    header
+                )
+            }
+            Error::CommitParse(error) => {
+                write!(f, "Invalid commit message: {}", error)
+            }
+            Error::EmptyCommitMessage => {
+                write!(f, "Aborting commit due to empty commit message.")
+            }
+            Error::Editor => {
+                write!(f, "Some error happened while running the editor.")
+            }
+            Error::Changelog(path) => {
+                write!(f, "Could not update the changelog at {}", path)
+            }
+            Error::Version(version) => {
+                write!(f, "{} is not a valid semantic version", version)
+            }
+            Error::NothingToRelease => {
+                write!(
+                    f,
+                    "No feat, fix, perf or breaking commits since the last release."
+                )
+            }
+            Error::VersionFile(path) => {
+                write!(f, "Could not read or update the version in {}", path)
+            }
+            Error::DirtyTree => {
+                write!(
+                    f,
+                    "The working tree has uncommitted changes. Commit or stash them first."
+                )
+            }
+            Error::SpecialBranch(name) => {
+                write!(f, "{} is not a ticket branch", name)
+            }
+            Error::Merge(name) => {
+                write!(
+                    f,
+                    "Merging {} failed. Resolve the conflicts, commit and delete the branch by hand.",
+                    name
+                )
+            }
+            Error::State(name) => {
+                write!(f, "Could not read or write the state of git sam {}", name)
+            }
+            Error::FlowPaused(name, reason) => {
+                write!(
+                    f,
+                    "{}. Resolve the conflicts and run git sam {} --continue, or git sam {} --abort.",
+                    reason, name, name
+                )
+            }
+            Error::FlowInProgress(name) => {
+                write!(
+                    f,
+                    "A git sam {} is in progress. Run git sam {} --continue or --abort first.",
+                    name, name
+                )
+            }
+            Error::NoFlowInProgress(name) => {
+                write!(f, "No git sam {} is in progress", name)
+            }
+            Error::BranchExists(name) => {
+                write!(f, "A branch named {} already exists", name)
+            }
+            Error::CurrentBranch(name) => {
+                write!(
+                    f,
+                    "{} is checked out. Switch to another branch first.",
+                    name
+                )
+            }
+            Error::Unmerged(names) => {
+                write!(
+                    f,
+                    "Refusing to delete unmerged work, use --force to delete anyway:\n  {}",
+                    names.join("\n  ")
+                )
+            }
+            Error::DuplicateCode(code, conflicts) => {
+                write!(
+                    f,
+                    "The code {} is already used by:\n  {}\nUse --force to create the branch anyway, or --continue-ticket for a follow-up branch.",
+                    code,
+                    conflicts.join("\n  ")
+                )
+            }
+            Error::AmbiguousCode(code, matches) => {
+                let matches = matches
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| format!("  {}. {}", i + 1, name))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                write!(
+                    f,
+                    "Several branches carry the code {}:\n{}\nNarrow it down with --type or choose one with --pick N.",
+                    code, matches
+                )
+            }
+            Error::AmbiguousTitle(query, matches) => {
+                let matches = matches
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| format!("  {}. {}", i + 1, name))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                write!(
+                    f,
+                    "Several branch titles match {}, best first:\n{}\nNarrow it down with --type or choose one with --pick N.",
+                    query, matches
+                )
+            }
+            Error::NoTitleMatch(query) => {
+                write!(f, "No branch title matches {}", query)
+            }
+            Error::InvalidPick(pick, count) => {
+                write!(
+                    f,
+                    "--pick {} is out of range, there are {} matches",
+                    pick, count
+                )
+            }
+            Error::Json(message) => write!(f, "Could not write JSON: {}", message),
+            Error::StashPop => {
+                write!(
+                    f,
+                    "Restoring the stashed changes conflicted. They are kept in git stash."
+                )
+            }
+            Error::StashNotFound(sha) => {
+                write!(
+                    f,
+                    "The changes stashed before syncing ({}) are no longer in git stash.",
+                    sha
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommitParseError {
+    Empty,
+    EmptyType,
+    InvalidType(String),
+    UnclosedScope,
+    EmptyScope,
+    InvalidScope(String),
+    InvalidCode(String),
+    MultipleCodes,
+    MissingSeparator,
+    EmptySubject,
+    MissingBlankLine,
+}
+
+impl From<CommitParseError> for Error {
+    fn from(error: CommitParseError) -> Self {
+        Error::CommitParse(error)
+    }
+}
+
+impl fmt::Display for CommitParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommitParseError::Empty => write!(f, "the message is empty."),
+            CommitParseError::EmptyType => write!(f, "the header has no commit type."),
+            CommitParseError::InvalidType(commit_type) => {
+                write!(f, "{} is not a valid commit type.", commit_type)
+            }
+            CommitParseError::UnclosedScope => write!(f, "the scope is missing its closing ')'."),
+            CommitParseError::EmptyScope => write!(f, "the scope has an empty part."),
+            CommitParseError::InvalidScope(scope) => write!(f, "({}) is not a valid scope.", scope),
+            CommitParseError::InvalidCode(code) => {
+                write!(f, "{} is not a valid branch code.", code)
+            }
+            CommitParseError::MultipleCodes => {
+                write!(f, "the scope has more than one branch code.")
+            }
+            CommitParseError::MissingSeparator => {
+                write!(f, "the header is missing the \": \" before the message.")
+            }
+            CommitParseError::EmptySubject => write!(f, "the header has no message."),
+            CommitParseError::MissingBlankLine => {
+                write!(f, "the header must be followed by an empty line.")
+            }
         }
     }
 }
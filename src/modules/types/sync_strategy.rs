@@ -0,0 +1,19 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, ValueEnum, PartialEq, Debug, Default, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncStrategy {
+    #[default]
+    Rebase,
+    Merge,
+}
+
+impl SyncStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyncStrategy::Rebase => "rebase",
+            SyncStrategy::Merge => "merge",
+        }
+    }
+}